@@ -1,9 +1,11 @@
-use crate::cli::Cli;
+use crate::cli::{Cli, DateRangeArgs, NoteSelectorArgs};
 use crate::config::Config;
 use crate::config_manager::ConfigManager;
+use crate::date_time::{Clock, DateRange, Period, TimeExpr, Zone};
 use crate::errors::JourneyError;
+use crate::note::{Note, NoteSelector, TaskStatus};
 use crate::vault::Vault;
-use chrono::{Local, NaiveDate};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate};
 use std::env;
 use std::process::Command;
 
@@ -54,6 +56,7 @@ pub struct CliArgs {
 pub struct App {
     config_manager: ConfigManager,
     config: Config,
+    clock: Clock,
 }
 
 impl App {
@@ -71,6 +74,7 @@ impl App {
         Ok(Self {
             config_manager,
             config,
+            clock: Clock::from_env()?,
         })
     }
 
@@ -81,9 +85,16 @@ impl App {
         Ok(Self {
             config_manager,
             config,
+            clock: Clock::from_env()?,
         })
     }
 
+    /// Read "now" from `clock` instead of the system clock (or `JOURNEY_NOW`)
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     pub fn run(&mut self, cli: Cli) -> Result<(), JourneyError> {
         match cli.command {
             Some(cmd) => {
@@ -129,6 +140,12 @@ impl App {
             crate::journeyctl::Commands::Today { vault, verbose } => {
                 self.show_today_file(vault, verbose)
             }
+            crate::journeyctl::Commands::Import { from, path, vault } => {
+                self.import_foreign(from, &path, vault)
+            }
+            crate::journeyctl::Commands::Publish { vault, out } => {
+                self.publish(vault, &out)
+            }
         }
     }
 
@@ -136,7 +153,7 @@ impl App {
         match cmd {
             crate::cli::Commands::Add { content } => {
                 let cli_args = CliArgs { vault, date, relative_date, time, time_format };
-                self.add_note(&content, &cli_args, None)
+                self.add_note(&content, &cli_args, None, false, None)
             }
             crate::cli::Commands::List => {
                 let cli_args = CliArgs { vault, date, relative_date, time, time_format };
@@ -146,33 +163,89 @@ impl App {
                 let cli_args = CliArgs { vault, date, relative_date, time, time_format };
                 self.edit_notes(&cli_args)
             }
+            crate::cli::Commands::Search { query, tag, regex, case_sensitive, vault, all_vaults, category, range } => {
+                let query = crate::search::SearchQuery::new(query.as_deref().unwrap_or(""), regex, case_sensitive)?;
+                self.search_notes(&query, tag.as_deref(), vault, all_vaults, category.as_deref(), &range)
+            }
+            crate::cli::Commands::Tags { vault, category, range } => {
+                self.list_tags(vault, category.as_deref(), &range)
+            }
+            crate::cli::Commands::Export { options } => {
+                self.export_notes(options)
+            }
+            crate::cli::Commands::Import { format, file, vault } => {
+                self.import_notes(format, &file, vault)
+            }
+            crate::cli::Commands::ImportCalendar { file, date, vault } => {
+                self.import_calendar(&file, date, vault)
+            }
+            crate::cli::Commands::Todo { vault, category, range } => {
+                self.list_todos(vault, category.as_deref(), &range)
+            }
+            crate::cli::Commands::Digest { week, month: _, date, vault, write } => {
+                self.digest(if week { Period::Week } else { Period::Month }, date, vault, write)
+            }
+            crate::cli::Commands::Cal { month, vault } => {
+                self.show_calendar(month.as_deref(), vault)
+            }
+            crate::cli::Commands::Heatmap { year, vault } => {
+                self.show_heatmap(year.flatten(), vault)
+            }
+            crate::cli::Commands::Onthisday { date, recent, category, vault } => {
+                self.on_this_day(date, recent, category.as_deref(), vault)
+            }
+            crate::cli::Commands::Stats { vault, json, range } => {
+                self.show_stats(vault, json, &range)
+            }
+            crate::cli::Commands::Done { selector } => {
+                self.complete_task(&selector)
+            }
+            crate::cli::Commands::Rm { selector } => {
+                self.remove_note(&selector)
+            }
+            crate::cli::Commands::Amend { selector, content } => {
+                self.amend_note(&selector, &content.join(" "))
+            }
+            crate::cli::Commands::Mv { selector, to_date, to_time, to_category, to_vault } => {
+                self.move_note(&selector, to_date, to_time, to_category, to_vault)
+            }
         }
     }
 
     fn handle_default_behavior(&mut self, cli: &Cli) -> Result<(), JourneyError> {
-        let cli_args = CliArgs {
+        let mut cli_args = CliArgs {
             vault: cli.vault.clone(),
             date: cli.date.clone(),
             relative_date: cli.relative_date,
             time: cli.time.clone(),
             time_format: cli.time_format.clone(),
         };
+        if let Some(zone) = cli.tz {
+            self.override_timezone(cli.vault.as_deref(), zone)?;
+        }
+        if let Some(at) = &cli.at {
+            let (date, time) = self.split_date_time(&cli_args, at)?;
+            cli_args.date = date;
+            cli_args.time = Some(time);
+        }
         
         if cli.list {
-            self.list_notes(&cli_args, cli.header, cli.category.as_deref())
+            self.list_notes_or_period(&cli_args, cli)
         } else if cli.edit {
             self.edit_notes(&cli_args)
         } else if cli.stdin {
-            self.handle_stdin_input(&cli_args, cli.category.as_deref())
+            self.handle_stdin_input(&cli_args, cli.category.as_deref(), cli.task, cli.period)
+        } else if cli.stdin_single {
+            self.handle_stdin_single(&cli_args, cli.category.as_deref(), cli.task, cli.period)
         } else if let Some(note) = &cli.add_note {
-            self.add_note(note, &cli_args, cli.category.as_deref())
+            self.add_note(note, &cli_args, cli.category.as_deref(), cli.task, cli.period)
         } else if !cli.note_content.is_empty() {
             // Default behavior: treat note_content as note content
             let content = cli.note_content.join(" ");
-            self.add_note(&content, &cli_args, cli.category.as_deref())
+            self.add_note(&content, &cli_args, cli.category.as_deref(), cli.task, cli.period)
         } else {
             // Default behavior: list today's notes (same as --list)
-            self.list_notes(&cli_args, cli.header, cli.category.as_deref())
+            self.list_notes_or_period(&cli_args, cli)
         }
     }
 
@@ -222,6 +295,16 @@ impl App {
             monthly_format: None,
             quarterly_format: None,
             yearly_format: None,
+            weekly_template: None,
+            monthly_template: None,
+            quarterly_template: None,
+            yearly_template: None,
+            first_day_of_week: None,
+            named_times: std::collections::HashMap::new(),
+            timezone: None,
+            record_utc_offset: false,
+            day_starts_at: None,
+            sections: std::collections::BTreeMap::new(),
             note_format: None,
         };
 
@@ -260,13 +343,13 @@ impl App {
 
         // Print plugin detection results
         println!("🔍 Detected Obsidian plugins:");
-        if let Some(_) = &plugin_configs.daily_notes {
+        if plugin_configs.daily_notes.is_some() {
             println!("Daily Notes (core plugin) - enabled");
         } else {
             println!("Daily Notes (core plugin) - not enabled");
         }
 
-        if let Some(_) = &plugin_configs.periodic_notes {
+        if plugin_configs.periodic_notes.is_some() {
             println!("Periodic Notes plugin - enabled");
         } else {
             println!("Periodic Notes plugin - not enabled");
@@ -305,6 +388,16 @@ impl App {
             monthly_format: None,
             quarterly_format: None,
             yearly_format: None,
+            weekly_template: None,
+            monthly_template: None,
+            quarterly_template: None,
+            yearly_template: None,
+            first_day_of_week: None,
+            named_times: std::collections::HashMap::new(),
+            timezone: None,
+            record_utc_offset: false,
+            day_starts_at: None,
+            sections: std::collections::BTreeMap::new(),
             note_format: None,
         };
 
@@ -471,7 +564,7 @@ impl App {
 
         // Check if plugin is enabled (Journals plugin doesn't have a simple enabled field)
         // We'll consider it enabled if it has journals configured
-        if !plugin_data.get("journals").is_some() {
+        if plugin_data.get("journals").is_none() {
             return Err(JourneyError::Config("Journals plugin not configured".to_string()));
         }
 
@@ -524,55 +617,487 @@ impl App {
         Ok(())
     }
 
-    fn add_note(&mut self, content: &str, cli: &CliArgs, category: Option<&str>) -> Result<(), JourneyError> {
+    fn add_note(&mut self, content: &str, cli: &CliArgs, category: Option<&str>, task: bool, period: Option<Period>) -> Result<(), JourneyError> {
         let vault = self.get_vault(cli.vault.as_deref())?;
         let date = self.parse_date(cli)?;
-        let time = self.parse_time(cli)?;
-        
-        let timestamp = if let Some(time) = time {
-            vault.date_handler.combine_date_time(date, time)
+        // Use current time with the specified date (or current date if not specified)
+        let time = self.parse_time(cli)?.unwrap_or(TimeExpr::Offset(chrono::Duration::zero()));
+        let timestamp = self.resolve_time(&vault, date, time)?;
+        let date = vault.date_handler.logical_date(timestamp.naive_local());
+
+        if let Some(period) = period {
+            let status = task.then_some(TaskStatus::Open);
+            vault.add_period_note(period, content, status, timestamp, category)?;
+            println!("{} added to {}", if task { "Task" } else { "Note" }, period.label(date, vault.config.week_starts_on()));
+        } else if task {
+            vault.add_task_with_category(content, Some(timestamp), category)?;
+            println!("Task added successfully!");
         } else {
-            // Use current time with the specified date (or current date if not specified)
-            let current_time = vault.date_handler.get_current_datetime().time();
-            vault.date_handler.combine_date_time(date, current_time)
-        };
-
-        vault.add_note_with_category(content, Some(timestamp), category)?;
-        println!("Note added successfully!");
+            vault.add_note_with_category(content, Some(timestamp), category)?;
+            println!("Note added successfully!");
+        }
         Ok(())
     }
 
     fn list_notes(&self, cli: &CliArgs, header: bool, category: Option<&str>) -> Result<(), JourneyError> {
+        self.list_notes_tagged(cli, header, category, None)
+    }
+
+    fn list_notes_tagged(&self, cli: &CliArgs, header: bool, category: Option<&str>, tag: Option<&str>) -> Result<(), JourneyError> {
         let vault = self.get_vault(cli.vault.as_deref())?;
         let date = self.parse_date(cli)?;
         
-        let notes = vault.list_notes_with_category(date, category)?;
-        
-        if notes.is_empty() {
-            println!("No notes found for {}", vault.date_handler.format_date(date));
-        } else {
-            let has_table_format = notes.iter().any(|note| note.trim().starts_with("|"));
+        let notes = filter_by_tag(vault.list_notes_with_category(date, category)?, tag);
+        print_listing(&vault, &vault.date_handler.format_date(date), &notes, header);
+        Ok(())
+    }
+
+    fn list_notes_or_period(&self, cli_args: &CliArgs, cli: &Cli) -> Result<(), JourneyError> {
+        match cli.period {
+            Some(period) => self.list_period_notes(cli_args, period, cli.header, cli.category.as_deref(), cli.tag.as_deref()),
+            None => self.list_notes_or_range(cli_args, &cli.range, cli.header, cli.category.as_deref(), cli.tag.as_deref()),
+        }
+    }
+
+    fn list_period_notes(&self, cli: &CliArgs, period: Period, header: bool, category: Option<&str>, tag: Option<&str>) -> Result<(), JourneyError> {
+        let vault = self.get_vault(cli.vault.as_deref())?;
+        let date = self.parse_date(cli)?;
+
+        let notes = filter_by_tag(vault.list_period_notes(period, date, category)?, tag);
+        print_listing(&vault, &period.label(date, vault.config.week_starts_on()), &notes, header);
+        Ok(())
+    }
+
+    fn list_notes_or_range(&self, cli: &CliArgs, range: &DateRangeArgs, header: bool, category: Option<&str>, tag: Option<&str>) -> Result<(), JourneyError> {
+        match self.resolve_date_range(cli, range)? {
+            Some(range) => self.list_notes_in_range(cli, range, header, category, tag),
+            None => self.list_notes_tagged(cli, header, category, tag),
+        }
+    }
+
+    fn list_notes_in_range(&self, cli: &CliArgs, range: DateRange, header: bool, category: Option<&str>, tag: Option<&str>) -> Result<(), JourneyError> {
+        let vault = self.get_vault(cli.vault.as_deref())?;
+        let mut found_any = false;
 
-            // Suppress "Notes for" message when in table mode
-            if !header && !has_table_format {
-                println!("Notes for {}:", vault.date_handler.format_date(date));
+        for date in range.days() {
+            // Days without a file (or without notes in the section) are skipped silently
+            let notes = filter_by_tag(vault.list_notes_with_category(date, category)?, tag);
+            if notes.is_empty() {
+                continue;
+            }
+
+            if found_any {
+                println!();
             }
+            found_any = true;
+
+            println!("Notes for {}:", vault.date_handler.format_date(date));
 
             // If header flag is set and we have table format notes, include table headers
-            if header && has_table_format {
+            if header && notes.iter().any(|note| note.trim().starts_with('|')) {
                 let (time_header, content_header) = vault.get_table_headers();
                 println!("| {} | {} |", time_header, content_header);
                 println!("|------|----------|");
             }
-            
+
             for note in notes {
                 println!("{}", note);
             }
         }
-        
+
+        if !found_any {
+            println!("No notes found between {} and {}",
+                vault.date_handler.format_date(range.start),
+                vault.date_handler.format_date(range.end));
+        }
+
+        Ok(())
+    }
+
+    /// The vault to operate on, or every configured vault (sorted by name)
+    fn vault_names(&self, vault: Option<String>, all_vaults: bool) -> Vec<Option<String>> {
+        if all_vaults {
+            let mut names: Vec<String> = self.config.vaults.keys().cloned().collect();
+            names.sort();
+            names.into_iter().map(Some).collect()
+        } else {
+            vec![vault]
+        }
+    }
+
+    fn search_notes(&self, query: &crate::search::SearchQuery, tag: Option<&str>, vault: Option<String>, all_vaults: bool, category: Option<&str>, range: &DateRangeArgs) -> Result<(), JourneyError> {
+        let mut hit_count = 0;
+        for vault_name in self.vault_names(vault, all_vaults) {
+            let cli_args = CliArgs { vault: vault_name, date: None, relative_date: None, time: None, time_format: None };
+            let vault = self.get_vault(cli_args.vault.as_deref())?;
+            let date_range = self.resolve_date_range(&cli_args, range)?;
+
+            let hits = crate::search::search_vault(&vault, query, date_range, category)?;
+            for note in hits.into_iter().filter(|note| tag.is_none_or(|t| note.has_tag(t))) {
+                let prefix = if all_vaults { format!("{}: ", vault.config.name) } else { String::new() };
+                let section = note.section.as_ref().map(|s| format!(" [{}]", s)).unwrap_or_default();
+                println!("{}{} {}{} {}",
+                    prefix,
+                    vault.date_handler.format_date(note.date),
+                    note.timestamp.as_deref().unwrap_or("--:--:--"),
+                    section,
+                    note.content);
+                hit_count += 1;
+            }
+        }
+
+        if hit_count == 0 {
+            println!("No matching notes found");
+        }
+
+        Ok(())
+    }
+
+    fn list_tags(&self, vault: Option<String>, category: Option<&str>, range: &DateRangeArgs) -> Result<(), JourneyError> {
+        let cli_args = CliArgs { vault, date: None, relative_date: None, time: None, time_format: None };
+        let vault = self.get_vault(cli_args.vault.as_deref())?;
+        let date_range = self.resolve_date_range(&cli_args, range)?;
+
+        let notes = vault.collect_notes(date_range, category)?;
+        let tags = crate::tags::tag_index(&notes);
+
+        if tags.is_empty() {
+            println!("No tags found");
+            return Ok(());
+        }
+
+        let width = tags.iter().map(|t| t.tag.chars().count() + 1).max().unwrap_or(0);
+        for summary in tags {
+            println!("{:<width$}  {:>5}  {}  {}",
+                format!("#{}", summary.tag),
+                summary.count,
+                vault.date_handler.format_date(summary.first_seen),
+                vault.date_handler.format_date(summary.last_seen),
+                width = width);
+        }
+
+        Ok(())
+    }
+
+    fn show_calendar(&self, month: Option<&str>, vault: Option<String>) -> Result<(), JourneyError> {
+        use std::io::IsTerminal;
+
+        let vault = self.get_vault(vault.as_deref())?;
+        let first_of_month = match month {
+            Some(month) => NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d")
+                .map_err(|_| JourneyError::InvalidDateFormat(format!("Invalid month: {} (expected YYYY-MM)", month)))?,
+            None => vault.date_handler.today(),
+        };
+
+        let counts = crate::calendar::day_counts(&vault, DateRange::month_of(first_of_month))?;
+        let highlight = std::io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none();
+        print!("{}", crate::calendar::render_month(first_of_month.year(), first_of_month.month(), &counts, vault.config.week_starts_on(), highlight));
+        Ok(())
+    }
+
+    fn show_heatmap(&self, year: Option<i32>, vault: Option<String>) -> Result<(), JourneyError> {
+        let vault = self.get_vault(vault.as_deref())?;
+        let year = year.unwrap_or_else(|| vault.date_handler.today().year());
+        let range = Period::Year.range(NaiveDate::from_ymd_opt(year, 1, 1)
+            .ok_or_else(|| JourneyError::InvalidDateFormat(format!("Invalid year: {}", year)))?, vault.config.week_starts_on());
+
+        let counts = crate::calendar::day_counts(&vault, range)?;
+        println!("{} {}", vault.config.name, year);
+        print!("{}", crate::calendar::render_heatmap(range, &counts, vault.config.week_starts_on()));
+        Ok(())
+    }
+
+    fn on_this_day(&self, date: Option<String>, recent: bool, category: Option<&str>, vault: Option<String>) -> Result<(), JourneyError> {
+        let cli_args = CliArgs { vault, date, relative_date: None, time: None, time_format: None };
+        let vault = self.get_vault(cli_args.vault.as_deref())?;
+        let date = self.parse_date(&cli_args)?;
+
+        let earliest_year = vault.daily_note_files()?.first().map_or(date.year(), |(first, _)| first.year());
+        let mut found_any = false;
+
+        for lookback in crate::onthisday::lookback_days(date, earliest_year, recent) {
+            if !vault.get_note_path(lookback.date).exists() {
+                continue;
+            }
+            let notes = vault.list_notes_with_category(lookback.date, category)?;
+            if notes.is_empty() {
+                continue;
+            }
+
+            if found_any {
+                println!();
+            }
+            found_any = true;
+            println!("{} ({} {})", lookback.label, lookback.date.format("%A"), vault.date_handler.format_date(lookback.date));
+            for note in notes {
+                println!("{}", note);
+            }
+        }
+
+        if !found_any {
+            println!("Nothing written on {} in earlier years", date.format("%B %-d"));
+        }
         Ok(())
     }
 
+    fn show_stats(&self, vault: Option<String>, json: bool, range: &DateRangeArgs) -> Result<(), JourneyError> {
+        let cli_args = CliArgs { vault, date: None, relative_date: None, time: None, time_format: None };
+        let vault = self.get_vault(cli_args.vault.as_deref())?;
+        let date_range = self.resolve_date_range(&cli_args, range)?;
+
+        let notes = vault.collect_notes(date_range, None)?;
+        let today = vault.date_handler.today();
+        let stats = crate::stats::Stats::compute(&notes, &vault.config, date_range.map_or(today, |r| r.end.min(today)));
+
+        if json {
+            println!("{}", serde_json::to_string_pretty(&stats)?);
+            return Ok(());
+        }
+
+        let (Some(first), Some(last)) = (stats.first_day, stats.last_day) else {
+            println!("No notes found");
+            return Ok(());
+        };
+
+        let days = |n: usize| if n == 1 { "1 day".to_string() } else { format!("{} days", n) };
+        println!("Statistics for {} ({} to {})", vault.config.name, vault.date_handler.format_date(first), vault.date_handler.format_date(last));
+        println!();
+        println!("Days with entries:  {}", stats.days_with_entries);
+        println!("Notes:              {}", stats.notes);
+        println!("Current streak:     {}", days(stats.current_streak));
+        println!("Longest streak:     {}", days(stats.longest_streak));
+        println!("Notes per day:      {:.1} mean, {} max", stats.notes_per_day_mean, stats.notes_per_day_max);
+        if let Some(hour) = stats.busiest_hour {
+            println!("Busiest hour:       {:02}:00-{:02}:00 ({} notes)", hour, (hour + 1) % 24, stats.busiest_hour_notes);
+        }
+        println!("Words:              {} ({:.1} per note)", stats.words, stats.words_per_note_mean);
+        println!();
+        println!("By section:");
+
+        let width = stats.sections.iter().map(|s| s.section.chars().count()).max().unwrap_or(0);
+        for section in &stats.sections {
+            let category = section.category.as_deref().map(|c| format!(" ({})", c)).unwrap_or_default();
+            println!("  {:<width$}  {:>5} notes  {:>6} words{}", section.section, section.notes, section.words, category, width = width);
+        }
+
+        Ok(())
+    }
+
+    fn export_notes(&self, options: crate::cli::ExportArgs) -> Result<(), JourneyError> {
+        let crate::cli::ExportArgs { format, vault, all_vaults, category, range, output, duration } = options;
+        let mut records = Vec::new();
+        for vault_name in self.vault_names(vault, all_vaults) {
+            let cli_args = CliArgs { vault: vault_name, date: None, relative_date: None, time: None, time_format: None };
+            let vault = self.get_vault(cli_args.vault.as_deref())?;
+            let date_range = self.resolve_date_range(&cli_args, &range)?;
+
+            records.extend(vault.collect_notes(date_range, category.as_deref())?
+                .iter()
+                .map(|note| crate::export::NoteRecord::from_note(&vault, note)));
+        }
+
+        let write = |out: &mut dyn std::io::Write| match format {
+            crate::export::ExportFormat::Ics => {
+                let duration = duration.map(|minutes| chrono::Duration::minutes(minutes.into()));
+                crate::ics::write_calendar(&records, duration, out)
+            }
+            _ => crate::export::write_records(&records, format, out),
+        };
+
+        match output {
+            Some(path) => {
+                let mut file = std::io::BufWriter::new(std::fs::File::create(&path)?);
+                write(&mut file)?;
+                println!("Exported {} notes to {}", records.len(), path.display());
+            }
+            None => write(&mut std::io::stdout().lock())?,
+        }
+        Ok(())
+    }
+
+    fn import_notes(&self, format: crate::import::ImportFormat, file: &std::path::Path, vault: Option<String>) -> Result<(), JourneyError> {
+        let vault = self.get_vault(vault.as_deref())?;
+        let records = crate::import::read_records(std::fs::File::open(file)?, format)?;
+        let summary = crate::import::import_records(&vault, &records)?;
+        print_import_summary(&summary);
+        Ok(())
+    }
+
+    fn import_foreign(&self, from: crate::import::ForeignJournal, path: &std::path::Path, vault: Option<String>) -> Result<(), JourneyError> {
+        let vault = self.get_vault(vault.as_deref())?;
+        let notes = crate::import::read_foreign(path, from, vault.date_handler.zone)?;
+        let summary = crate::import::import_notes(&vault, notes)?;
+        print_import_summary(&summary);
+        Ok(())
+    }
+
+    fn publish(&self, vault: Option<String>, out: &std::path::Path) -> Result<(), JourneyError> {
+        let vault = self.get_vault(vault.as_deref())?;
+        let summary = crate::publish::publish(&vault, out)?;
+        println!("Published {} days ({} pages) to {}", summary.days, summary.pages, out.display());
+        Ok(())
+    }
+
+    fn digest(&self, period: Period, date: Option<String>, vault: Option<String>, write: bool) -> Result<(), JourneyError> {
+        let cli_args = CliArgs { vault, date, relative_date: None, time: None, time_format: None };
+        let vault = self.get_vault(cli_args.vault.as_deref())?;
+        let date = self.parse_date(&cli_args)?;
+
+        let digest = crate::digest::Digest::build(&vault, period, date)?;
+        let markdown = digest.to_markdown(&vault);
+
+        if write {
+            let path = vault.write_period_section(period, date, crate::digest::DIGEST_HEADING, &markdown)?;
+            println!("Wrote digest of {} notes to {}", digest.note_count(), path.display());
+        } else {
+            print!("{}", markdown);
+        }
+        Ok(())
+    }
+
+    fn import_calendar(&self, file: &std::path::Path, date: Option<String>, vault: Option<String>) -> Result<(), JourneyError> {
+        let cli_args = CliArgs { vault, date, relative_date: None, time: None, time_format: None };
+        let vault = self.get_vault(cli_args.vault.as_deref())?;
+        let date = self.parse_date(&cli_args)?;
+
+        let events = crate::ics::parse_calendar(&std::fs::read_to_string(file)?, vault.date_handler.zone);
+        // Meetings go to the meetings section when the vault has one
+        let category = vault.config.find_category("meetings").ok().map(|(category, _)| category.to_string());
+        // The logical day runs from day_starts_at until the same time the next morning
+        let day_start = vault.date_handler.day_start;
+        let meetings = crate::ics::events_on(&events, date).into_iter().filter(|(start, _)| start.time() >= day_start)
+            .chain(crate::ics::events_on(&events, date + chrono::Duration::days(1)).into_iter().filter(|(start, _)| start.time() < day_start));
+        let notes: Vec<crate::vault::PendingNote> = meetings
+            .map(|(start, summary)| {
+                let datetime = vault.date_handler.resolve_time_expression(TimeExpr::At(start.time()), date);
+                crate::vault::PendingNote {
+                    content: summary,
+                    timestamp: vault.date_handler.combine_date_time(datetime.date(), datetime.time()),
+                    category: category.clone(),
+                }
+            })
+            .collect();
+
+        let day = vault.date_handler.format_date(date);
+        if notes.is_empty() {
+            println!("No meetings on {}", day);
+            return Ok(());
+        }
+
+        let summary = crate::import::import_notes(&vault, notes)?;
+        println!("Imported {} meetings for {}, skipped {} already present", summary.added(), day, summary.skipped());
+        Ok(())
+    }
+
+    fn list_todos(&self, vault: Option<String>, category: Option<&str>, range: &DateRangeArgs) -> Result<(), JourneyError> {
+        let cli_args = CliArgs { vault, date: None, relative_date: None, time: None, time_format: None };
+        let vault = self.get_vault(cli_args.vault.as_deref())?;
+        let date_range = self.resolve_date_range(&cli_args, range)?;
+
+        let tasks: Vec<Note> = vault.collect_notes(date_range, category)?
+            .into_iter()
+            .filter(Note::is_open_task)
+            .collect();
+
+        if tasks.is_empty() {
+            println!("No open tasks");
+            return Ok(());
+        }
+
+        for task in &tasks {
+            println!("{} {} [ ] {}",
+                vault.date_handler.format_date(task.date),
+                task.timestamp.as_deref().unwrap_or("--:--:--"),
+                task.content);
+        }
+        println!("{} open task{}", tasks.len(), if tasks.len() == 1 { "" } else { "s" });
+
+        Ok(())
+    }
+
+    fn complete_task(&self, args: &NoteSelectorArgs) -> Result<(), JourneyError> {
+        let (vault, note) = self.select_note(args)?;
+        let done = vault.complete_task(&note, vault.date_handler.get_current_datetime())?;
+        println!("Done: {}", done.trim());
+        Ok(())
+    }
+
+    fn remove_note(&self, args: &NoteSelectorArgs) -> Result<(), JourneyError> {
+        let (vault, note) = self.select_note(args)?;
+        let removed = vault.remove_note(&note)?;
+        println!("Removed: {}", removed.trim());
+        Ok(())
+    }
+
+    fn amend_note(&self, args: &NoteSelectorArgs, content: &str) -> Result<(), JourneyError> {
+        let (vault, note) = self.select_note(args)?;
+        let before = vault.note_text(&note)?;
+        let after = vault.amend_note(&note, content)?;
+        println!("Before: {}", before.trim());
+        println!("After:  {}", after.trim());
+        Ok(())
+    }
+
+    fn move_note(&self, args: &NoteSelectorArgs, to_date: Option<String>, to_time: Option<String>, to_category: Option<String>, to_vault: Option<String>) -> Result<(), JourneyError> {
+        let (source, note) = self.select_note(args)?;
+        let text = source.note_text(&note)?;
+
+        let target_args = CliArgs {
+            vault: to_vault.or_else(|| Some(source.config.name.clone())),
+            date: to_date,
+            relative_date: None,
+            time: to_time,
+            time_format: None,
+        };
+        let target = self.get_vault(target_args.vault.as_deref())?;
+        let date = match target_args.date {
+            Some(_) => self.parse_date(&target_args)?,
+            None => note.date,
+        };
+        let time = match self.parse_time(&target_args)? {
+            Some(time) => time,
+            None => TimeExpr::At(note.time().ok_or_else(|| JourneyError::InvalidTimeFormat(
+                "The note has no timestamp; pass --to-time".to_string()
+            ))?),
+        };
+        let category = to_category.or_else(|| args.category.clone());
+
+        let timestamp = self.resolve_time(&target, date, time)?;
+        source.move_note(&note, &target, timestamp, category.as_deref())?;
+
+        println!("Moved: {}", text.trim());
+        println!("   to: {} {} in vault '{}'",
+            target.date_handler.format_date(target.date_handler.logical_date(timestamp.naive_local())),
+            target.date_handler.format_time(timestamp.time()),
+            target.config.name);
+        Ok(())
+    }
+
+    /// Resolve the vault and the single note a selector refers to
+    pub fn select_note(&self, args: &NoteSelectorArgs) -> Result<(Vault, Note), JourneyError> {
+        let cli_args = CliArgs {
+            vault: args.vault.clone(),
+            date: args.date.clone(),
+            relative_date: None,
+            time: None,
+            time_format: None,
+        };
+        let vault = self.get_vault(cli_args.vault.as_deref())?;
+        let date = self.parse_date(&cli_args)?;
+
+        let selector = if let Some(at) = &args.at {
+            let time = vault.date_handler.parse_time(at)?;
+            NoteSelector::Time { time, with_seconds: at.matches(':').count() >= 2 }
+        } else if let Some(index) = args.index {
+            NoteSelector::Index(index)
+        } else {
+            NoteSelector::Last
+        };
+
+        let note = vault.select_note(date, args.category.as_deref(), &selector)?;
+        Ok((vault, note))
+    }
+
     fn edit_notes(&self, cli: &CliArgs) -> Result<(), JourneyError> {
         let vault = self.get_vault(cli.vault.as_deref())?;
         let date = self.parse_date(cli)?;
@@ -627,31 +1152,115 @@ impl App {
             }
         };
 
-        Ok(Vault::new(vault_config.clone()))
+        Ok(Vault::new(vault_config.clone()).with_clock(self.clock))
     }
 
     pub fn parse_date(&self, cli: &CliArgs) -> Result<NaiveDate, JourneyError> {
         if let Some(date_str) = &cli.date {
+            if date_str.contains("..") {
+                return Err(JourneyError::InvalidDateFormat(format!(
+                    "Date ranges are only supported when listing notes: {}", date_str
+                )));
+            }
             let vault = self.get_vault(cli.vault.as_deref())?;
             vault.date_handler.parse_date_with_format_override(date_str, vault.config.date_format.as_deref())
         } else if let Some(days_ago) = cli.relative_date {
             let vault = self.get_vault(cli.vault.as_deref())?;
-            Ok(vault.date_handler.parse_relative_date(days_ago))
+            vault.date_handler.parse_relative_date(days_ago)
         } else {
-            Ok(Local::now().date_naive())
+            let vault = self.get_vault(cli.vault.as_deref())?;
+            Ok(vault.date_handler.today())
         }
     }
 
-    fn parse_time(&self, cli: &CliArgs) -> Result<Option<chrono::NaiveTime>, JourneyError> {
+    /// Resolve the date range selected by `--date START..END` or the range flags, if any
+    pub fn resolve_date_range(&self, cli: &CliArgs, range: &DateRangeArgs) -> Result<Option<DateRange>, JourneyError> {
+        let date_is_range = cli.date.as_deref().is_some_and(|d| d.contains(".."));
+        if !date_is_range && !range.is_set() {
+            return Ok(None);
+        }
+
+        let vault = self.get_vault(cli.vault.as_deref())?;
+        let date_format = vault.config.date_format.as_deref();
+
+        if let Some(date_str) = cli.date.as_deref().filter(|_| date_is_range) {
+            return vault.date_handler.parse_date_range(date_str, date_format).map(Some);
+        }
+
+        if let Some(from) = &range.from {
+            let start = vault.date_handler.parse_date_with_format_override(from, date_format)?;
+            let end = match &range.to {
+                Some(to) => vault.date_handler.parse_date_with_format_override(to, date_format)?,
+                None => vault.date_handler.today(),
+            };
+            return DateRange::new(start, end).map(Some);
+        }
+
+        // The remaining shorthands are relative to --date/--relative-date, or today
+        let reference = self.parse_date(cli)?;
+        if let Some(days) = range.last {
+            if days == 0 {
+                return Err(JourneyError::InvalidDateFormat("--last must be at least 1 day".to_string()));
+            }
+            Ok(Some(DateRange::last_days(reference, days)))
+        } else if range.week {
+            Ok(Some(DateRange::week_of(reference, vault.config.week_starts_on())))
+        } else {
+            Ok(Some(DateRange::month_of(reference)))
+        }
+    }
+
+    fn parse_time(&self, cli: &CliArgs) -> Result<Option<TimeExpr>, JourneyError> {
         if let Some(time_str) = &cli.time {
             let vault = self.get_vault(cli.vault.as_deref())?;
-            Ok(Some(vault.date_handler.parse_time_with_format_override(time_str, cli.time_format.as_deref())?))
+            Ok(Some(vault.date_handler.parse_time_expression(time_str, cli.time_format.as_deref(), &vault.config.named_times)?))
         } else {
             Ok(None)
         }
     }
 
-    fn handle_stdin_input(&mut self, cli: &CliArgs, category: Option<&str>) -> Result<(), JourneyError> {
+    /// Use `zone` for the vault's clock and entered times for the rest of this run,
+    /// recording each note's UTC offset so the wall time stays unambiguous
+    fn override_timezone(&mut self, vault_name: Option<&str>, zone: Zone) -> Result<(), JourneyError> {
+        let name = self.get_vault(vault_name)?.config.name;
+        if let Some(config) = self.config.vaults.values_mut().find(|config| config.name == name) {
+            config.timezone = Some(zone);
+            config.record_utc_offset = true;
+        }
+        Ok(())
+    }
+
+    /// The timestamp a time expression refers to on `date`, against the vault's clock
+    fn resolve_time(&self, vault: &Vault, date: NaiveDate, time: TimeExpr) -> Result<DateTime<FixedOffset>, JourneyError> {
+        let resolved = vault.date_handler.resolve_time_expression(time, date);
+        vault.date_handler.resolve_date_time(resolved.date(), resolved.time())
+    }
+
+    /// Split an `--at` value such as "yesterday 16:45" or "last friday at lunch"
+    /// into its date and time parts; a bare time ("16:45", "now") is today
+    fn split_date_time(&self, cli: &CliArgs, at: &str) -> Result<(Option<String>, String), JourneyError> {
+        let vault = self.get_vault(cli.vault.as_deref())?;
+        let words: Vec<&str> = at.split_whitespace().collect();
+        let is_time = |time: &str| vault.date_handler.parse_time_expression(time, cli.time_format.as_deref(), &vault.config.named_times).is_ok();
+
+        if is_time(at) {
+            return Ok((None, at.to_string()));
+        }
+        // The date is the shortest leading run of words that parses; the rest must be a time
+        for split in 1..words.len() {
+            let date = words[..split].join(" ");
+            let time = words[split..].join(" ");
+            let date_ok = vault.date_handler.parse_date_with_format_override(&date, vault.config.date_format.as_deref()).is_ok();
+            if date_ok && is_time(&time) {
+                return Ok((Some(date), time));
+            }
+        }
+        Err(JourneyError::InvalidDateFormat(format!(
+            "Could not read '{}' as a date followed by a time (e.g. \"yesterday 16:45\")", at
+        )))
+    }
+
+    fn handle_stdin_input(&mut self, cli: &CliArgs, category: Option<&str>, task: bool, period: Option<Period>) -> Result<(), JourneyError> {
         use std::io::{self, BufRead};
         
         let stdin = io::stdin();
@@ -669,7 +1278,7 @@ impl App {
             }
             
             // Add each line as a separate note
-            self.add_note(trimmed, cli, category)?;
+            self.add_note(trimmed, cli, category, task, period)?;
             note_count += 1;
         }
         
@@ -682,6 +1291,23 @@ impl App {
         Ok(())
     }
 
+    fn handle_stdin_single(&mut self, cli: &CliArgs, category: Option<&str>, task: bool, period: Option<Period>) -> Result<(), JourneyError> {
+        use std::io::{self, Read};
+
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+
+        let content = crate::note::multiline_content(&input);
+        if content.is_empty() {
+            println!("No content received from stdin");
+            return Ok(());
+        }
+
+        self.add_note(&content, cli, category, task, period)?;
+        println!("Added a {}-line note from stdin", content.lines().count());
+        Ok(())
+    }
+
     fn get_system_locale(&self) -> String {
         // Try to get locale from environment
         env::var("LANG")
@@ -732,7 +1358,7 @@ impl App {
 
         // Remove vault from config
         self.config.remove_vault(vault_name)
-            .map_err(|e| JourneyError::Config(e))?;
+            .map_err(JourneyError::Config)?;
 
         // Save updated config
         self.config_manager.save_config(&self.config)?;
@@ -767,6 +1393,9 @@ impl App {
             if let Some(section_header) = &vault.section_header {
                 println!("    Section: {}", section_header);
             }
+            for (category, section) in vault.categories() {
+                println!("    Category {}: {}", category, section.header);
+            }
             if let Some(template_file) = &vault.template_file {
                 println!("    Template: {}", template_file);
             }
@@ -790,7 +1419,7 @@ impl App {
 
     fn show_today_file(&self, vault_name: Option<String>, verbose: bool) -> Result<(), JourneyError> {
         let vault = self.get_vault(vault_name.as_deref())?;
-        let today = vault.date_handler.get_current_datetime().date_naive();
+        let today = vault.date_handler.today();
         let file_path = vault.get_note_path(today);
         
         if verbose {
@@ -808,3 +1437,48 @@ impl App {
         Ok(())
     }
 }
+
+/// Print one day's (or period's) note lines as `--list` shows them
+fn print_listing(vault: &Vault, title: &str, notes: &[String], header: bool) {
+    if notes.is_empty() {
+        println!("No notes found for {}", title);
+        return;
+    }
+
+    let has_table_format = notes.iter().any(|note| note.trim().starts_with("|"));
+
+    // Suppress "Notes for" message when in table mode
+    if !header && !has_table_format {
+        println!("Notes for {}:", title);
+    }
+
+    // If header flag is set and we have table format notes, include table headers
+    if header && has_table_format {
+        let (time_header, content_header) = vault.get_table_headers();
+        println!("| {} | {} |", time_header, content_header);
+        println!("|------|----------|");
+    }
+
+    for note in notes {
+        println!("{}", note);
+    }
+}
+
+/// Print the files an import created or updated, followed by totals
+fn print_import_summary(summary: &crate::import::ImportSummary) {
+    for outcome in summary.files.iter().filter(|f| f.added > 0) {
+        println!("{} {} (+{})",
+            if outcome.created { "Created:" } else { "Updated:" },
+            outcome.path.display(),
+            outcome.added);
+    }
+    println!("Imported {} notes, skipped {} duplicates", summary.added(), summary.skipped());
+}
+
+/// Keep only note lines carrying the tag (all lines when no tag is given)
+fn filter_by_tag(notes: Vec<String>, tag: Option<&str>) -> Vec<String> {
+    match tag {
+        Some(tag) => notes.into_iter().filter(|line| crate::note::has_tag(line, tag)).collect(),
+        None => notes,
+    }
+}
@@ -0,0 +1,120 @@
+use crate::date_time::DateRange;
+use crate::errors::JourneyError;
+use crate::vault::Vault;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::BTreeMap;
+
+/// Intensity levels of the heatmap, from no notes to the busiest days
+const HEAT: [char; 5] = ['·', '░', '▒', '▓', '█'];
+
+const REVERSE: &str = "\x1b[7m";
+const RESET: &str = "\x1b[0m";
+
+/// Note counts for the days in a range that have a daily file
+///
+/// Files are located with `get_note_path`, so custom `file_path_format` layouts work.
+pub fn day_counts(vault: &Vault, range: DateRange) -> Result<BTreeMap<NaiveDate, usize>, JourneyError> {
+    let mut counts = BTreeMap::new();
+    for date in range.days() {
+        if vault.get_note_path(date).exists() {
+            counts.insert(date, vault.read_all_notes(date)?.len());
+        }
+    }
+    Ok(counts)
+}
+
+/// Weekdays in display order, starting at `first`
+fn week_days(first: Weekday) -> Vec<Weekday> {
+    std::iter::successors(Some(first), |day| Some(day.succ())).take(7).collect()
+}
+
+/// Days between the start of the week (as configured) and the date
+fn weekday_offset(date: NaiveDate, first: Weekday) -> i64 {
+    ((date.weekday().num_days_from_monday() + 7 - first.num_days_from_monday()) % 7) as i64
+}
+
+/// Render a month as a calendar grid
+///
+/// Days with a daily file show their note count in parentheses; with `highlight`
+/// they are also drawn in reverse video.
+pub fn render_month(year: i32, month: u32, counts: &BTreeMap<NaiveDate, usize>, first: Weekday, highlight: bool) -> String {
+    let Some(start) = NaiveDate::from_ymd_opt(year, month, 1) else {
+        return String::new();
+    };
+    let range = DateRange::month_of(start);
+    const CELL: usize = 7;
+
+    let title = start.format("%B %Y").to_string();
+    let mut out = vec![format!("{:^width$}", title, width = CELL * 7).trim_end().to_string()];
+    out.push(week_days(first).iter().map(|day| format!("{:<CELL$}", &day.to_string()[..2])).collect::<String>().trim_end().to_string());
+
+    let mut line = " ".repeat(CELL * weekday_offset(start, first) as usize);
+    for date in range.days() {
+        let day = format!("{:>2}", date.day());
+        let cell = match counts.get(&date) {
+            Some(count) if highlight => format!("{}{}{}{:<5}", REVERSE, day, RESET, format!("({})", count)),
+            Some(count) => format!("{}{:<5}", day, format!("({})", count)),
+            None => format!("{:<CELL$}", day),
+        };
+        line.push_str(&cell);
+
+        if weekday_offset(date, first) == 6 {
+            out.push(line.trim_end().to_string());
+            line = String::new();
+        }
+    }
+    if !line.trim().is_empty() {
+        out.push(line.trim_end().to_string());
+    }
+
+    out.join("\n") + "\n"
+}
+
+/// Render a GitHub-style contribution grid: one row per weekday, one column per week
+///
+/// Shading is relative to the busiest day in the range; days outside the range are blank.
+pub fn render_heatmap(range: DateRange, counts: &BTreeMap<NaiveDate, usize>, first: Weekday) -> String {
+    let grid_start = range.start - Duration::days(weekday_offset(range.start, first));
+    let weeks = ((range.end - grid_start).num_days() / 7 + 1) as usize;
+    let max = counts.values().copied().max().unwrap_or(0);
+
+    // Month labels above the first week that contains the 1st of the month
+    let mut header = vec![' '; weeks * 2];
+    for week in 0..weeks {
+        let week_start = grid_start + Duration::days(week as i64 * 7);
+        let first_of_month = (0..7).map(|d| week_start + Duration::days(d)).find(|d| d.day() == 1 && range.contains(*d));
+        if let Some(date) = first_of_month {
+            for (i, c) in date.format("%b").to_string().chars().enumerate() {
+                if let Some(slot) = header.get_mut(week * 2 + i) {
+                    *slot = c;
+                }
+            }
+        }
+    }
+
+    let mut out = vec![format!("    {}", header.iter().collect::<String>().trim_end())];
+    for (row, weekday) in week_days(first).iter().enumerate() {
+        let label = if row % 2 == 0 { weekday.to_string() } else { String::new() };
+        let mut line = format!("{:<4}", label);
+        for week in 0..weeks {
+            let date = grid_start + Duration::days(week as i64 * 7 + row as i64);
+            let cell = if !range.contains(date) {
+                ' '
+            } else {
+                match counts.get(&date).copied().unwrap_or(0) {
+                    0 => HEAT[0],
+                    count => HEAT[(count * 4).div_ceil(max).clamp(1, 4)],
+                }
+            };
+            line.push(cell);
+            line.push(' ');
+        }
+        out.push(line.trim_end().to_string());
+    }
+
+    let days = counts.values().filter(|c| **c > 0).count();
+    let notes: usize = counts.values().sum();
+    out.push(String::new());
+    out.push(format!("{} notes on {} days    less {} more", notes, days, HEAT.iter().collect::<String>()));
+    out.join("\n") + "\n"
+}
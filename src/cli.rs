@@ -1,4 +1,8 @@
-use clap::{Parser, Subcommand};
+use crate::date_time::Period;
+use crate::export::ExportFormat;
+use crate::import::ImportFormat;
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use std::path::PathBuf;
 
 fn parse_relative_date(s: &str) -> Result<i64, String> {
     s.parse::<i64>().map_err(|_| format!("Invalid relative date: {}", s))
@@ -25,7 +29,7 @@ pub struct Cli {
     #[arg(short, long)]
     pub edit: bool,
 
-    /// Date selector (YYYY-MM-DD format)
+    /// Date selector (YYYY-MM-DD format, or START..END when listing)
     #[arg(short, long)]
     pub date: Option<String>,
 
@@ -33,10 +37,18 @@ pub struct Cli {
     #[arg(short, long, value_parser = parse_relative_date)]
     pub relative_date: Option<i64>,
 
-    /// Time selector (HH:MM or HH:MM:SS format)
-    #[arg(short, long)]
+    /// Time selector (HH:MM, "noon", a named time like "at lunch", or relative: "-20m", "2h ago", "now")
+    #[arg(short, long, allow_hyphen_values = true)]
     pub time: Option<String>,
 
+    /// Date and time in one go, e.g. "yesterday 16:45"
+    #[arg(long, conflicts_with_all = ["date", "relative_date", "time"])]
+    pub at: Option<String>,
+
+    /// Time zone for this note ("Asia/Tokyo", "+09:00"); the note records its UTC offset
+    #[arg(long, value_name = "ZONE")]
+    pub tz: Option<crate::date_time::Zone>,
+
     /// Time format override (12h|24h)
     #[arg(long)]
     pub time_format: Option<String>,
@@ -45,11 +57,23 @@ pub struct Cli {
     #[arg(long)]
     pub stdin: bool,
 
+    /// Add the note as an open task ("- [ ] ...")
+    #[arg(long)]
+    pub task: bool,
+
+    /// Read all of stdin as a single multi-line note
+    #[arg(long, visible_alias = "multiline", conflicts_with = "stdin")]
+    pub stdin_single: bool,
+
+    /// Write to (or list) the weekly, monthly, quarterly or yearly note instead of the daily one
+    #[arg(long, value_enum, conflicts_with_all = ["from", "week", "month", "last", "edit"])]
+    pub period: Option<Period>,
+
     /// Vault name to use (optional if only one vault exists)
     #[arg(short = 'V', long)]
     pub vault: Option<String>,
 
-    /// Category for section selection (e.g., 'work' uses section_header_work)
+    /// Category for section selection, as configured under `sections` (or one of its aliases)
     #[arg(short = 'c', long)]
     pub category: Option<String>,
 
@@ -57,6 +81,13 @@ pub struct Cli {
     #[arg(long)]
     pub header: bool,
 
+    /// Only list notes carrying this hashtag (e.g. 'deploy' or '#deploy')
+    #[arg(long)]
+    pub tag: Option<String>,
+
+    #[command(flatten)]
+    pub range: DateRangeArgs,
+
     /// Show version information
     #[arg(short = 'v', long = "version")]
     pub version: bool,
@@ -66,6 +97,98 @@ pub struct Cli {
     pub note_content: Vec<String>,
 }
 
+/// Date range selection shared by listing-style commands
+#[derive(Args, Debug, Clone, Default)]
+pub struct DateRangeArgs {
+    /// Start of a date range (inclusive)
+    #[arg(long, conflicts_with_all = ["week", "month", "last"])]
+    pub from: Option<String>,
+
+    /// End of a date range (inclusive, defaults to today)
+    #[arg(long, requires = "from")]
+    pub to: Option<String>,
+
+    /// Select the week containing the date (Monday to Sunday unless the vault sets first_day_of_week)
+    #[arg(long, conflicts_with_all = ["month", "last"])]
+    pub week: bool,
+
+    /// Select the calendar month containing the date
+    #[arg(long, conflicts_with = "last")]
+    pub month: bool,
+
+    /// Select the last N days, including today
+    #[arg(long, value_name = "DAYS")]
+    pub last: Option<u32>,
+}
+
+impl DateRangeArgs {
+    /// Whether any range flag was given
+    pub fn is_set(&self) -> bool {
+        self.from.is_some() || self.week || self.month || self.last.is_some()
+    }
+}
+
+/// Options for `journey export`
+#[derive(Args, Debug, Clone)]
+pub struct ExportArgs {
+    /// Output format
+    #[arg(long, value_enum, default_value = "json")]
+    pub format: ExportFormat,
+
+    /// Vault name to export
+    #[arg(short = 'V', long)]
+    pub vault: Option<String>,
+
+    /// Export every configured vault
+    #[arg(long, conflicts_with = "vault")]
+    pub all_vaults: bool,
+
+    /// Only export the section for this category
+    #[arg(short = 'c', long)]
+    pub category: Option<String>,
+
+    #[command(flatten)]
+    pub range: DateRangeArgs,
+
+    /// Write to a file instead of stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Event length in minutes for the ics format (events have no length by default)
+    #[arg(long, value_name = "MINUTES")]
+    pub duration: Option<u32>,
+}
+
+/// Selects a single note for commands that modify one entry
+#[derive(Args, Debug, Clone, Default)]
+#[group(skip)]
+#[command(group(ArgGroup::new("selector").required(true).args(["at", "index", "last"])))]
+pub struct NoteSelectorArgs {
+    /// Date of the note (defaults to today)
+    #[arg(short, long)]
+    pub date: Option<String>,
+
+    /// Select the note written at this time (HH:MM or HH:MM:SS)
+    #[arg(long)]
+    pub at: Option<String>,
+
+    /// Select the note by its position in the --list output (1-based)
+    #[arg(long)]
+    pub index: Option<usize>,
+
+    /// Select the latest note of the day
+    #[arg(long)]
+    pub last: bool,
+
+    /// Vault name to use
+    #[arg(short = 'V', long)]
+    pub vault: Option<String>,
+
+    /// Category whose section the note is listed under
+    #[arg(short = 'c', long)]
+    pub category: Option<String>,
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
     /// Add a note
@@ -77,5 +200,210 @@ pub enum Commands {
     List,
     /// Edit notes
     Edit,
+    /// Search note content across a vault
+    Search {
+        /// Text to search for (case-insensitive substring by default)
+        #[arg(required_unless_present = "tag")]
+        query: Option<String>,
+
+        /// Only match notes carrying this hashtag
+        #[arg(long)]
+        tag: Option<String>,
+
+        /// Treat the query as a regular expression
+        #[arg(long)]
+        regex: bool,
+
+        /// Match case exactly
+        #[arg(long)]
+        case_sensitive: bool,
+
+        /// Vault name to search
+        #[arg(short = 'V', long)]
+        vault: Option<String>,
+
+        /// Search every configured vault
+        #[arg(long, conflicts_with = "vault")]
+        all_vaults: bool,
+
+        /// Only search the section for this category
+        #[arg(short = 'c', long)]
+        category: Option<String>,
+
+        #[command(flatten)]
+        range: DateRangeArgs,
+    },
+    /// Export notes as JSON, NDJSON, CSV or iCalendar
+    Export {
+        #[command(flatten)]
+        options: ExportArgs,
+    },
+    /// Import timestamped notes from CSV or NDJSON (fields: date, time, content, category)
+    Import {
+        /// Input format
+        #[arg(long, value_enum)]
+        format: ImportFormat,
+
+        /// File to read records from
+        file: PathBuf,
+
+        /// Vault name to import into
+        #[arg(short = 'V', long)]
+        vault: Option<String>,
+    },
+    /// Add the day's meetings from an iCalendar file to the meetings section
+    ImportCalendar {
+        /// Calendar file (.ics)
+        file: PathBuf,
+
+        /// Day to import (defaults to today)
+        #[arg(short, long)]
+        date: Option<String>,
+
+        /// Vault name to use
+        #[arg(short = 'V', long)]
+        vault: Option<String>,
+    },
+    /// List open tasks across days
+    Todo {
+        /// Vault name to use
+        #[arg(short = 'V', long)]
+        vault: Option<String>,
+
+        /// Only list tasks in the section for this category
+        #[arg(short = 'c', long)]
+        category: Option<String>,
+
+        #[command(flatten)]
+        range: DateRangeArgs,
+    },
+    /// Summarize a week or month of notes as markdown, grouped by category and day
+    #[command(group(ArgGroup::new("span").required(true).args(["week", "month"])))]
+    Digest {
+        /// Summarize the week containing the date (Monday to Sunday unless the vault sets first_day_of_week)
+        #[arg(long)]
+        week: bool,
+
+        /// Summarize the calendar month containing the date
+        #[arg(long)]
+        month: bool,
+
+        /// Any date within the period (defaults to today)
+        #[arg(short, long)]
+        date: Option<String>,
+
+        /// Vault name to use
+        #[arg(short = 'V', long)]
+        vault: Option<String>,
+
+        /// Write the digest into the weekly or monthly note instead of printing it
+        #[arg(long)]
+        write: bool,
+    },
+    /// Tick off an open task, recording when it was completed
+    Done {
+        #[command(flatten)]
+        selector: NoteSelectorArgs,
+    },
+    /// Remove a single note
+    Rm {
+        #[command(flatten)]
+        selector: NoteSelectorArgs,
+    },
+    /// Replace the content of a single note, keeping its timestamp
+    Amend {
+        #[command(flatten)]
+        selector: NoteSelectorArgs,
+
+        /// The new note content
+        #[arg(required = true, trailing_var_arg = true)]
+        content: Vec<String>,
+    },
+    /// Move a single note to another date, time, category or vault
+    #[command(group(ArgGroup::new("target").required(true).multiple(true).args(["to_date", "to_time", "to_category", "to_vault"])))]
+    Mv {
+        #[command(flatten)]
+        selector: NoteSelectorArgs,
+
+        /// Target date
+        #[arg(long)]
+        to_date: Option<String>,
+
+        /// Target time (HH:MM or HH:MM:SS)
+        #[arg(long)]
+        to_time: Option<String>,
+
+        /// Target category (section)
+        #[arg(long)]
+        to_category: Option<String>,
+
+        /// Target vault
+        #[arg(long)]
+        to_vault: Option<String>,
+    },
+    /// List every hashtag in a vault with counts and first/last-seen dates
+    Tags {
+        /// Vault name to index
+        #[arg(short = 'V', long)]
+        vault: Option<String>,
+
+        /// Only index the section for this category
+        #[arg(short = 'c', long)]
+        category: Option<String>,
+
+        #[command(flatten)]
+        range: DateRangeArgs,
+    },
+    /// Print a month calendar with note counts for days that have a daily file
+    Cal {
+        /// Month to show (YYYY-MM, defaults to the current month)
+        #[arg(long, value_name = "YYYY-MM")]
+        month: Option<String>,
+
+        /// Vault name to use
+        #[arg(short = 'V', long)]
+        vault: Option<String>,
+    },
+    /// Print a contribution heatmap of a year's notes
+    Heatmap {
+        /// Year to show (defaults to the current year)
+        #[arg(long, value_name = "YEAR")]
+        year: Option<Option<i32>>,
+
+        /// Vault name to use
+        #[arg(short = 'V', long)]
+        vault: Option<String>,
+    },
+    /// Show what was written on the same date in earlier years
+    Onthisday {
+        /// Date to look back from (defaults to today)
+        #[arg(short, long)]
+        date: Option<String>,
+
+        /// Also show the same weekday one week, one month (4 weeks) and one quarter (13 weeks) ago
+        #[arg(long)]
+        recent: bool,
+
+        /// Only show notes from this category's section
+        #[arg(short = 'c', long)]
+        category: Option<String>,
+
+        /// Vault name to use
+        #[arg(short = 'V', long)]
+        vault: Option<String>,
+    },
+    /// Show journaling statistics: streaks, notes per day, busiest hour, words and sections
+    Stats {
+        /// Vault name to analyze
+        #[arg(short = 'V', long)]
+        vault: Option<String>,
+
+        /// Print the statistics as JSON
+        #[arg(long)]
+        json: bool,
+
+        #[command(flatten)]
+        range: DateRangeArgs,
+    },
 }
 
@@ -1,15 +1,63 @@
+use crate::date_time::{Period, Zone};
+use crate::errors::JourneyError;
+use chrono::{NaiveTime, Weekday};
 use serde::{Deserialize, Serialize, Deserializer};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 use std::env;
 
+/// Categories with a legacy `section_header_*` setting, migrated into `sections` on load
+pub const CATEGORIES: [&str; 4] = ["work", "personal", "health", "meetings"];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TableHeaders {
     pub time: String,
     pub content: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+/// Where a category's notes go in the daily note
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(from = "SectionSpec")]
+pub struct SectionConfig {
+    /// Section heading text, e.g. "Work"
+    pub header: String,
+    /// Other names accepted for `-c`, e.g. "job"
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// Heading level used when the section is created (1 for "#", 2 for "##"); defaults to 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<usize>,
+    /// Position in digests and published indexes, lowest first; unordered categories follow by name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<i32>,
+}
+
+/// A section given as just its header (`work: Work`) or in full
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SectionSpec {
+    Header(String),
+    Full {
+        header: String,
+        #[serde(default)]
+        aliases: Vec<String>,
+        #[serde(default)]
+        level: Option<usize>,
+        #[serde(default)]
+        order: Option<i32>,
+    },
+}
+
+impl From<SectionSpec> for SectionConfig {
+    fn from(spec: SectionSpec) -> Self {
+        match spec {
+            SectionSpec::Header(header) => Self { header, ..Self::default() },
+            SectionSpec::Full { header, aliases, level, order } => Self { header, aliases, level, order },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
 pub struct Config {
     pub vaults: HashMap<String, VaultConfig>,
     pub default_vault: Option<String>,
@@ -36,27 +84,49 @@ pub struct VaultConfig {
     pub list_type: Option<NoteFormat>,
     // Legacy/compat fields expected by older tests (not used by runtime)
     pub section_name: Option<String>,
+    pub note_format: Option<NoteFormat>,
+    // Periodic note paths (same placeholders as file_path_format) and templates
     pub weekly_format: Option<String>,
     pub monthly_format: Option<String>,
     pub quarterly_format: Option<String>,
     pub yearly_format: Option<String>,
-    pub note_format: Option<NoteFormat>,
+    #[serde(default, deserialize_with = "deserialize_template_file_with_expansion")]
+    pub weekly_template: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_template_file_with_expansion")]
+    pub monthly_template: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_template_file_with_expansion")]
+    pub quarterly_template: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_template_file_with_expansion")]
+    pub yearly_template: Option<String>,
+    /// First day of the week for calendar views, `--week` ranges and weekly notes (e.g. "monday", "sunday"); defaults to Monday
+    #[serde(default)]
+    pub first_day_of_week: Option<Weekday>,
+    /// Named times for `--time`, e.g. `lunch: "12:00"` enables `--time "at lunch"`
+    #[serde(default)]
+    pub named_times: HashMap<String, String>,
+    /// Time zone for the vault's clock and entered times ("Europe/Oslo", "+02:00"); defaults to the system zone
+    #[serde(default)]
+    pub timezone: Option<Zone>,
+    /// Write each note's UTC offset after its time ("[14:30:00 +02:00]")
+    #[serde(default)]
+    pub record_utc_offset: bool,
+    /// Time the logical day starts ("04:00"); earlier notes belong to the previous day
+    #[serde(default, deserialize_with = "deserialize_time_of_day")]
+    pub day_starts_at: Option<NaiveTime>,
+    /// Categories for `-c`, mapped to the section their notes go to
+    #[serde(default)]
+    pub sections: BTreeMap<String, SectionConfig>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub enum NoteFormat {
     #[serde(rename = "bullet")]
+    #[default]
     Bullet,
     #[serde(rename = "table")]
     Table,
 }
 
-impl Default for NoteFormat {
-    fn default() -> Self {
-        NoteFormat::Bullet
-    }
-}
-
 /// Custom deserializer for PathBuf that expands tildes
 fn deserialize_path_with_tilde_expansion<'de, D>(deserializer: D) -> Result<PathBuf, D::Error>
 where
@@ -75,13 +145,16 @@ where
     Ok(template_file.map(|path| expand_tilde(&path).to_string_lossy().to_string()))
 }
 
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            vaults: HashMap::new(),
-            default_vault: None,
-        }
-    }
+fn deserialize_time_of_day<'de, D>(deserializer: D) -> Result<Option<NaiveTime>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let time: Option<String> = Option::deserialize(deserializer)?;
+    time.map(|time| {
+        NaiveTime::parse_from_str(&time, "%H:%M")
+            .or_else(|_| NaiveTime::parse_from_str(&time, "%H:%M:%S"))
+            .map_err(|_| serde::de::Error::custom(format!("invalid time of day '{}', expected HH:MM", time)))
+    }).transpose()
 }
 
 impl<'de> Deserialize<'de> for Config {
@@ -109,6 +182,8 @@ impl<'de> Deserialize<'de> for Config {
                 let expanded = expand_tilde(&tpl).to_string_lossy().to_string();
                 vault.template_file = Some(expanded);
             }
+            vault.migrate_section_headers();
+            vault.validate_categories().map_err(serde::de::Error::custom)?;
         }
 
         Ok(Config { vaults: raw.vaults, default_vault: raw.default_vault })
@@ -123,7 +198,7 @@ impl<'de> Deserialize<'de> for Config {
 /// - Windows: %APPDATA%\path -> C:\Users\username\AppData\Roaming\path
 fn expand_tilde(path: &str) -> PathBuf {
     // First check for tilde expansion
-    if path.starts_with("~/") {
+    if let Some(rest) = path.strip_prefix("~/") {
         // Try to get home directory from environment variables
         let home_dir = if cfg!(windows) {
             // On Windows, try USERPROFILE first, then HOME
@@ -136,7 +211,7 @@ fn expand_tilde(path: &str) -> PathBuf {
         };
         
         if let Some(home) = home_dir {
-            PathBuf::from(home).join(&path[2..])
+            PathBuf::from(home).join(rest)
         } else {
             // If no home directory found, return the path as-is
             PathBuf::from(path)
@@ -222,24 +297,136 @@ impl VaultConfig {
             monthly_format: None,
             quarterly_format: None,
             yearly_format: None,
+            weekly_template: None,
+            monthly_template: None,
+            quarterly_template: None,
+            yearly_template: None,
+            first_day_of_week: None,
+            named_times: HashMap::new(),
+            timezone: None,
+            record_utc_offset: false,
+            day_starts_at: None,
+            sections: BTreeMap::new(),
             note_format: None,
         }
     }
 
-    /// Get the section header for a category, falling back to default section_header
-    pub fn get_section_header(&self, category: Option<&str>) -> Option<&String> {
-        if let Some(cat) = category {
-            // Try category-specific section headers
-            match cat {
-                "work" => return self.section_header_work.as_ref(),
-                "personal" => return self.section_header_personal.as_ref(),
-                "health" => return self.section_header_health.as_ref(),
-                "meetings" => return self.section_header_meetings.as_ref(),
-                _ => {} // Unknown category, fall through to default
+    /// Move the legacy `section_header_*` settings into `sections`, keeping their old order
+    ///
+    /// An entry already present in `sections` wins over the legacy field.
+    pub fn migrate_section_headers(&mut self) {
+        let legacy = [
+            self.section_header_work.take(),
+            self.section_header_personal.take(),
+            self.section_header_health.take(),
+            self.section_header_meetings.take(),
+        ];
+        for (order, (category, header)) in CATEGORIES.iter().zip(legacy).enumerate() {
+            if let Some(header) = header {
+                self.sections.entry(category.to_string())
+                    .or_insert(SectionConfig { header, order: Some(order as i32), ..SectionConfig::default() });
+            }
+        }
+    }
+
+    /// Configured categories in display order
+    pub fn categories(&self) -> Vec<(&str, &SectionConfig)> {
+        let mut categories: Vec<(&str, &SectionConfig)> = self.sections.iter()
+            .map(|(category, section)| (category.as_str(), section))
+            .collect();
+        // Sorted by name already; ordered categories go first
+        categories.sort_by_key(|(_, section)| (section.order.is_none(), section.order));
+        categories
+    }
+
+    /// The category called `name`, or having it as an alias (case-insensitive)
+    pub fn find_category(&self, name: &str) -> Result<(&str, &SectionConfig), JourneyError> {
+        let name = name.trim();
+        self.sections.iter()
+            .find(|(category, section)| {
+                category.eq_ignore_ascii_case(name) || section.aliases.iter().any(|alias| alias.eq_ignore_ascii_case(name))
+            })
+            .map(|(category, section)| (category.as_str(), section))
+            .ok_or_else(|| {
+                let valid: Vec<&str> = self.categories().into_iter().map(|(category, _)| category).collect();
+                JourneyError::UnknownCategory(if valid.is_empty() {
+                    format!("'{}' (no categories are configured; add them under sections in the vault configuration)", name)
+                } else {
+                    format!("'{}' (valid categories: {})", name, valid.join(", "))
+                })
+            })
+    }
+
+    /// Get the section header for a category, or the default section_header without one
+    pub fn get_section_header(&self, category: Option<&str>) -> Result<Option<&String>, JourneyError> {
+        match category {
+            Some(category) => self.find_category(category).map(|(_, section)| Some(&section.header)),
+            None => Ok(self.section_header.as_ref().or(self.section_name.as_ref())),
+        }
+    }
+
+    /// Heading level for a new section holding the category's notes
+    pub fn section_level(&self, category: Option<&str>) -> usize {
+        category
+            .and_then(|category| self.find_category(category).ok())
+            .and_then(|(_, section)| section.level)
+            .map_or(1, |level| level.clamp(1, 6))
+    }
+
+    /// The category whose configured section header matches a note's section heading
+    ///
+    /// Headings are compared whole and case-insensitively, ignoring their `#` markers.
+    pub fn category_for_section(&self, section: &str) -> Option<&str> {
+        let heading = |text: &str| text.trim().trim_start_matches('#').trim().to_lowercase();
+        let section = heading(section);
+        self.categories().into_iter()
+            .find(|(_, config)| heading(&config.header) == section)
+            .map(|(category, _)| category)
+    }
+
+    /// Check that no two categories share a name or alias (case-insensitive)
+    pub fn validate_categories(&self) -> Result<(), JourneyError> {
+        let mut seen: HashMap<String, &str> = HashMap::new();
+        for (category, section) in &self.sections {
+            let mut names: Vec<String> = std::iter::once(category).chain(&section.aliases)
+                .map(|name| name.trim().to_lowercase())
+                .collect();
+            names.sort();
+            names.dedup();
+            for name in names {
+                if let Some(other) = seen.insert(name.clone(), category) {
+                    return Err(JourneyError::Config(format!(
+                        "Vault '{}': '{}' is used by both the '{}' and '{}' categories", self.name, name, other, category
+                    )));
+                }
             }
         }
-        // Fall back to default section_header
-        self.section_header.as_ref().or(self.section_name.as_ref())
+        Ok(())
+    }
+
+    /// First day of the week for calendar views, week ranges and weekly notes
+    pub fn week_starts_on(&self) -> Weekday {
+        self.first_day_of_week.unwrap_or(Weekday::Mon)
+    }
+
+    /// Path format for a periodic note, if configured
+    pub fn period_format(&self, period: Period) -> Option<&String> {
+        match period {
+            Period::Week => self.weekly_format.as_ref(),
+            Period::Month => self.monthly_format.as_ref(),
+            Period::Quarter => self.quarterly_format.as_ref(),
+            Period::Year => self.yearly_format.as_ref(),
+        }
+    }
+
+    /// Template file for a new periodic note, if configured
+    pub fn period_template(&self, period: Period) -> Option<&String> {
+        match period {
+            Period::Week => self.weekly_template.as_ref(),
+            Period::Month => self.monthly_template.as_ref(),
+            Period::Quarter => self.quarterly_template.as_ref(),
+            Period::Year => self.yearly_template.as_ref(),
+        }
     }
 
     /// Create a test VaultConfig with minimal required fields
@@ -264,6 +451,16 @@ impl VaultConfig {
             monthly_format: None,
             quarterly_format: None,
             yearly_format: None,
+            weekly_template: None,
+            monthly_template: None,
+            quarterly_template: None,
+            yearly_template: None,
+            first_day_of_week: None,
+            named_times: HashMap::new(),
+            timezone: None,
+            record_utc_offset: false,
+            day_starts_at: None,
+            sections: BTreeMap::new(),
             note_format: None,
         }
     }
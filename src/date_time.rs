@@ -1,13 +1,38 @@
-use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Local, LocalResult, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc, Weekday};
+use chrono_tz::Tz;
 use crate::errors::JourneyError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
 
 pub struct DateTimeHandler {
     pub locale: String,
+    /// Zone used for "now" and for interpreting entered times
+    pub zone: Zone,
+    pub clock: Clock,
+    /// Time the logical day starts; earlier times belong to the previous day
+    pub day_start: NaiveTime,
 }
 
 impl DateTimeHandler {
     pub fn new(locale: String) -> Self {
-        Self { locale }
+        Self { locale, zone: Zone::Local, clock: Clock::System, day_start: NaiveTime::MIN }
+    }
+
+    pub fn with_zone(mut self, zone: Zone) -> Self {
+        self.zone = zone;
+        self
+    }
+
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn with_day_start(mut self, day_start: NaiveTime) -> Self {
+        self.day_start = day_start;
+        self
     }
 
     pub fn parse_date(&self, date_str: &str) -> Result<NaiveDate, JourneyError> {
@@ -15,6 +40,11 @@ impl DateTimeHandler {
     }
 
     pub fn parse_date_with_format_override(&self, date_str: &str, format_override: Option<&str>) -> Result<NaiveDate, JourneyError> {
+        // Relative and calendar expressions never clash with a fixed format
+        if let Some(date) = self.parse_natural_date(date_str, self.today()) {
+            return Ok(date);
+        }
+
         // If format override is specified, use only that format
         if let Some(override_format) = format_override {
             // Convert common format names to chrono format strings
@@ -48,7 +78,7 @@ impl DateTimeHandler {
                 "%B %d, %Y",    // US long: October 24, 2025
                 "%b %d, %Y",    // US short: Oct 24, 2025
             ]
-        } else if self.locale.starts_with("no") || self.locale.starts_with("nb") || self.locale.starts_with("nn") {
+        } else if self.is_norwegian() {
             // Norwegian formats (DD.MM.YYYY)
             vec![
                 "%Y-%m-%d",     // ISO: 2025-10-24
@@ -79,10 +109,80 @@ impl DateTimeHandler {
         )))
     }
 
+    /// Parse a relative or calendar date expression against `today`
+    ///
+    /// Accepts "today", "yesterday", "tomorrow", "last friday", "next monday",
+    /// "3 days ago", "2w ago", "in 2 days", ISO week dates ("2025-W43-5", "2025-W43")
+    /// and ordinal dates ("2025-297"). Norwegian locales also accept "i går",
+    /// "forrige fredag", "for 3 dager siden" and friends.
+    pub fn parse_natural_date(&self, input: &str, today: NaiveDate) -> Option<NaiveDate> {
+        let text = input.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+
+        parse_iso_week_date(&text)
+            .or_else(|| parse_ordinal_date(&text))
+            .or_else(|| ENGLISH_DATE_WORDS.parse(&text, today))
+            .or_else(|| if self.is_norwegian() { NORWEGIAN_DATE_WORDS.parse(&text, today) } else { None })
+    }
+
+    fn is_norwegian(&self) -> bool {
+        self.locale.starts_with("no") || self.locale.starts_with("nb") || self.locale.starts_with("nn")
+    }
+
+    /// Parse a date range written as "START..END" (either side in any accepted date format)
+    pub fn parse_date_range(&self, range_str: &str, format_override: Option<&str>) -> Result<DateRange, JourneyError> {
+        let (start, end) = range_str.split_once("..").ok_or_else(|| JourneyError::InvalidDateFormat(format!(
+            "Invalid date range: {}. Use START..END", range_str
+        )))?;
+        let start = self.parse_date_with_format_override(start.trim(), format_override)?;
+        let end = self.parse_date_with_format_override(end.trim(), format_override)?;
+        DateRange::new(start, end)
+    }
+
     pub fn parse_time(&self, time_str: &str) -> Result<NaiveTime, JourneyError> {
         self.parse_time_with_format_override(time_str, None)
     }
 
+    /// Parse a `--time` value: a clock time, "noon"/"midnight", a named time
+    /// ("lunch", "at lunch") from `named_times`, or an offset from now ("now",
+    /// "-20m", "2h ago", "in 15 minutes")
+    pub fn parse_time_expression(&self, input: &str, format_override: Option<&str>, named_times: &HashMap<String, String>) -> Result<TimeExpr, JourneyError> {
+        let text = input.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+        let norwegian = self.is_norwegian();
+
+        if text == "now" || (norwegian && text == "nå") {
+            return Ok(TimeExpr::Offset(Duration::zero()));
+        }
+        if text == "noon" {
+            return Ok(TimeExpr::At(NaiveTime::from_hms_opt(12, 0, 0).unwrap()));
+        }
+        if text == "midnight" || (norwegian && text == "midnatt") {
+            return Ok(TimeExpr::At(NaiveTime::MIN));
+        }
+        if let Some(offset) = parse_time_offset(&text, norwegian) {
+            // Offsets too large to apply to the current time fall through and are rejected
+            let now = self.get_current_datetime().naive_local();
+            if now.checked_add_signed(offset).is_some() {
+                return Ok(TimeExpr::Offset(offset));
+            }
+        }
+
+        let name = text.strip_prefix("at ").unwrap_or(&text);
+        let named = named_times.iter().find(|(key, _)| key.to_lowercase() == name);
+        if let Some((key, value)) = named {
+            return self.parse_time(value).map(TimeExpr::At).map_err(|_| JourneyError::InvalidTimeFormat(format!(
+                "Named time '{}' has an invalid time: {}", key, value
+            )));
+        }
+
+        if text.starts_with("at ") {
+            return Err(JourneyError::InvalidTimeFormat(format!(
+                "Unknown named time '{}'; add it under named_times in the vault configuration", name
+            )));
+        }
+
+        self.parse_time_with_format_override(input, format_override).map(TimeExpr::At)
+    }
+
     pub fn parse_time_with_format_override(&self, time_str: &str, format_override: Option<&str>) -> Result<NaiveTime, JourneyError> {
         // If format override is specified, use only that format
         if let Some(override_format) = format_override {
@@ -125,7 +225,7 @@ impl DateTimeHandler {
                 "%I:%M%p",      // 12-hour compact: 2:30PM
                 "%I:%M:%S%p",   // 12-hour compact with seconds: 2:30:45PM
             ]
-        } else if self.locale.starts_with("no") || self.locale.starts_with("nb") || self.locale.starts_with("nn") {
+        } else if self.is_norwegian() {
             // Norwegian formats
             vec![
                 "%H:%M",        // 24-hour: 14:30
@@ -159,16 +259,49 @@ impl DateTimeHandler {
         )))
     }
 
-    pub fn parse_relative_date(&self, days_offset: i64) -> NaiveDate {
-        let now = Local::now().date_naive();
-        now - chrono::Duration::days(days_offset)
+    pub fn parse_relative_date(&self, days_offset: i64) -> Result<NaiveDate, JourneyError> {
+        Duration::try_days(days_offset)
+            .and_then(|offset| self.today().checked_sub_signed(offset))
+            .ok_or_else(|| JourneyError::InvalidDateFormat(format!("Relative date is out of range: {} days", days_offset)))
+    }
+
+    /// The current logical day
+    pub fn today(&self) -> NaiveDate {
+        self.logical_date(self.get_current_datetime().naive_local())
+    }
+
+    /// The logical day a wall-clock time belongs to: times before the day start count towards the previous day
+    pub fn logical_date(&self, datetime: NaiveDateTime) -> NaiveDate {
+        if datetime.time() < self.day_start {
+            datetime.date() - Duration::days(1)
+        } else {
+            datetime.date()
+        }
+    }
+
+    /// Time of a note in its logical day's file; times after midnight run past 24:00 ("25:30:00")
+    pub fn format_note_time(&self, datetime: NaiveDateTime) -> String {
+        let days = (datetime.date() - self.logical_date(datetime)).num_days();
+        format!("{:02}{}", datetime.hour() as i64 + 24 * days, datetime.format(":%M:%S"))
+    }
+
+    /// The wall-clock time a `--time` value refers to on the logical day `date`
+    pub fn resolve_time_expression(&self, time: TimeExpr, date: NaiveDate) -> NaiveDateTime {
+        let now = self.get_current_datetime().naive_local();
+        let calendar_date = match time {
+            TimeExpr::At(at) if at < self.day_start => date + Duration::days(1),
+            TimeExpr::At(_) => date,
+            // Offsets count from "now" carried over to the chosen day
+            TimeExpr::Offset(_) => date + (now.date() - self.today()),
+        };
+        time.resolve(calendar_date, now)
     }
 
     pub fn format_date(&self, date: NaiveDate) -> String {
         date.format("%Y-%m-%d").to_string()
     }
 
-    pub fn format_datetime(&self, datetime: DateTime<Local>) -> String {
+    pub fn format_datetime<Z: TimeZone>(&self, datetime: DateTime<Z>) -> String where Z::Offset: fmt::Display {
         datetime.format("%H:%M:%S").to_string()
     }
 
@@ -176,13 +309,496 @@ impl DateTimeHandler {
         time.format("%H:%M:%S").to_string()
     }
 
-    pub fn get_current_datetime(&self) -> DateTime<Local> {
-        Local::now()
+    pub fn get_current_datetime(&self) -> DateTime<FixedOffset> {
+        match self.clock {
+            Clock::System => self.zone.from_utc(Utc::now()),
+            Clock::Fixed(instant) => self.zone.from_utc(instant),
+            Clock::FixedWallClock(naive) => self.combine_date_time(naive.date(), naive.time()),
+        }
+    }
+
+    /// Combine a date and time in the handler's zone
+    ///
+    /// A time skipped by a daylight saving change moves forward by the length of
+    /// the gap (02:30 on a spring-forward night becomes 03:30); a repeated time
+    /// takes its first occurrence. Use `resolve_date_time` to reject skipped times.
+    pub fn combine_date_time(&self, date: NaiveDate, time: NaiveTime) -> DateTime<FixedOffset> {
+        let naive = NaiveDateTime::new(date, time);
+        match self.zone.from_local(naive) {
+            LocalResult::Single(datetime) | LocalResult::Ambiguous(datetime, _) => datetime,
+            LocalResult::None => {
+                // Read the wall time with the offset in force before the gap
+                let offset = self.zone.from_local(naive - Duration::hours(3)).earliest().map_or(0, |before| before.offset().local_minus_utc());
+                self.zone.from_utc((naive - Duration::seconds(offset as i64)).and_utc())
+            }
+        }
+    }
+
+    /// Combine a date and time entered by the user in the handler's zone
+    ///
+    /// A repeated time (when clocks go back) takes its first occurrence; pass an
+    /// explicit offset zone such as "+01:00" to pick the second. A time skipped
+    /// when clocks go forward is an error.
+    pub fn resolve_date_time(&self, date: NaiveDate, time: NaiveTime) -> Result<DateTime<FixedOffset>, JourneyError> {
+        match self.zone.from_local(NaiveDateTime::new(date, time)) {
+            LocalResult::Single(datetime) | LocalResult::Ambiguous(datetime, _) => Ok(datetime),
+            LocalResult::None => Err(JourneyError::NonexistentTime(format!(
+                "{} {} is skipped by a daylight saving change in {}",
+                self.format_date(date), self.format_time(time), self.zone
+            ))),
+        }
+    }
+}
+
+/// Environment variable that pins "now", e.g. `JOURNEY_NOW=2025-10-24T14:30:00`
+pub const NOW_ENV: &str = "JOURNEY_NOW";
+
+/// Where "now" comes from
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Clock {
+    /// The system clock
+    #[default]
+    System,
+    /// A fixed instant ("2025-10-24T14:30:00+02:00")
+    Fixed(DateTime<Utc>),
+    /// A fixed wall-clock time in the vault's zone ("2025-10-24T14:30:00")
+    FixedWallClock(NaiveDateTime),
+}
+
+impl Clock {
+    /// The system clock, or a fixed clock when `JOURNEY_NOW` is set
+    pub fn from_env() -> Result<Self, JourneyError> {
+        match std::env::var(NOW_ENV) {
+            Ok(value) if !value.trim().is_empty() => value.parse().map_err(|e| JourneyError::Config(format!("{}: {}", NOW_ENV, e))),
+            _ => Ok(Clock::System),
+        }
+    }
+}
+
+impl FromStr for Clock {
+    type Err = String;
+
+    /// An RFC 3339 instant, or a date and time without offset ("2025-10-24T14:30[:00]", "2025-10-24 14:30")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(instant) = DateTime::parse_from_rfc3339(s) {
+            return Ok(Clock::Fixed(instant.to_utc()));
+        }
+        ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(s, format).ok())
+            .map(Clock::FixedWallClock)
+            .ok_or_else(|| format!("Invalid timestamp '{}'; use YYYY-MM-DDTHH:MM:SS", s))
+    }
+}
+
+/// A time zone for reading the clock and interpreting wall-clock times
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Zone {
+    /// The system time zone
+    #[default]
+    Local,
+    /// An IANA time zone such as "Europe/Oslo"
+    Named(Tz),
+    /// A fixed UTC offset such as "+02:00"
+    Fixed(FixedOffset),
+}
+
+impl Zone {
+    /// An instant as wall-clock time in this zone
+    pub fn from_utc(self, utc: DateTime<Utc>) -> DateTime<FixedOffset> {
+        match self {
+            Zone::Local => utc.with_timezone(&Local).fixed_offset(),
+            Zone::Named(tz) => utc.with_timezone(&tz).fixed_offset(),
+            Zone::Fixed(offset) => utc.with_timezone(&offset),
+        }
     }
 
-    pub fn combine_date_time(&self, date: NaiveDate, time: NaiveTime) -> DateTime<Local> {
-        let naive_dt = NaiveDateTime::new(date, time);
-        Local.from_local_datetime(&naive_dt).single().unwrap_or_else(|| Local::now())
+    /// The instants a wall-clock time refers to in this zone (none or two around DST changes)
+    pub fn from_local(self, naive: NaiveDateTime) -> LocalResult<DateTime<FixedOffset>> {
+        match self {
+            Zone::Local => Local.from_local_datetime(&naive).map(|datetime| datetime.fixed_offset()),
+            Zone::Named(tz) => tz.from_local_datetime(&naive).map(|datetime| datetime.fixed_offset()),
+            Zone::Fixed(offset) => offset.from_local_datetime(&naive),
+        }
     }
 }
 
+impl FromStr for Zone {
+    type Err = String;
+
+    /// "local", an IANA name ("Europe/Oslo", "UTC") or an offset ("+02:00", "-0530")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("local") {
+            return Ok(Zone::Local);
+        }
+        if s.starts_with(['+', '-']) {
+            let digits: String = s[1..].chars().filter(|c| *c != ':').collect();
+            let (hours, minutes) = match digits.len() {
+                2 => (digits.parse::<i32>().ok(), Some(0)),
+                4 => (digits[..2].parse::<i32>().ok(), digits[2..].parse::<i32>().ok()),
+                _ => (None, None),
+            };
+            let sign = if s.starts_with('-') { -1 } else { 1 };
+            return hours.zip(minutes)
+                .and_then(|(h, m)| FixedOffset::east_opt(sign * (h * 3600 + m * 60)))
+                .map(Zone::Fixed)
+                .ok_or_else(|| format!("Invalid UTC offset: {}", s));
+        }
+        s.parse::<Tz>().map(Zone::Named).map_err(|_| format!("Unknown time zone: {}", s))
+    }
+}
+
+impl fmt::Display for Zone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Zone::Local => write!(f, "local"),
+            Zone::Named(tz) => write!(f, "{}", tz.name()),
+            Zone::Fixed(offset) => write!(f, "{}", offset),
+        }
+    }
+}
+
+impl Serialize for Zone {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Zone {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+
+/// A parsed `--time` value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeExpr {
+    /// A time of day on the selected date
+    At(NaiveTime),
+    /// An offset from the current time ("-20m", "2h ago", "now")
+    Offset(Duration),
+}
+
+impl TimeExpr {
+    /// The timestamp this expression refers to on `date`, given the current time
+    ///
+    /// Offsets are applied to the current time of day on `date`, so "-20m" shortly
+    /// after midnight lands on the previous day.
+    pub fn resolve(self, date: NaiveDate, now: NaiveDateTime) -> NaiveDateTime {
+        match self {
+            TimeExpr::At(time) => date.and_time(time),
+            TimeExpr::Offset(offset) => date.and_time(now.time()) + offset,
+        }
+    }
+}
+
+/// "-20m", "+1h", "2h ago", "in 15 minutes" (and "for 20 min siden" / "om 2 timer" in Norwegian)
+fn parse_time_offset(text: &str, norwegian: bool) -> Option<Duration> {
+    let (amount, sign) = if let Some(amount) = text.strip_prefix('-') {
+        (amount, -1)
+    } else if let Some(amount) = text.strip_prefix('+') {
+        (amount, 1)
+    } else if let Some(amount) = text.strip_suffix(" ago") {
+        (amount, -1)
+    } else if let Some(amount) = text.strip_prefix("in ") {
+        (amount, 1)
+    } else if let Some(amount) = text.strip_suffix(" siden").filter(|_| norwegian) {
+        (amount.strip_prefix("for ").unwrap_or(amount), -1)
+    } else if let Some(amount) = text.strip_prefix("om ").filter(|_| norwegian) {
+        (amount, 1)
+    } else {
+        return None;
+    };
+
+    let digits = amount.find(|c: char| !c.is_ascii_digit())?;
+    let count: i64 = amount[..digits].parse().ok()?;
+    let minutes = match amount[digits..].trim() {
+        "m" | "min" | "mins" | "minute" | "minutes" | "minutt" | "minutter" => count,
+        "h" | "hr" | "hrs" | "hour" | "hours" | "t" | "time" | "timer" => count.checked_mul(60)?,
+        _ => return None,
+    };
+    Duration::try_minutes(sign * minutes)
+}
+
+/// "2025-W43-5" (Friday of ISO week 43) or "2025-W43" (its Monday)
+fn parse_iso_week_date(text: &str) -> Option<NaiveDate> {
+    let (year, rest) = text.split_once("-w")?;
+    let (week, day) = match rest.split_once('-') {
+        Some((week, day)) => (week, day.parse::<u32>().ok()?),
+        None => (rest, 1),
+    };
+    let weekday = Weekday::try_from(u8::try_from(day.checked_sub(1)?).ok()?).ok()?;
+    NaiveDate::from_isoywd_opt(year.parse().ok()?, week.parse().ok()?, weekday)
+}
+
+/// "2025-297" (the 297th day of 2025)
+fn parse_ordinal_date(text: &str) -> Option<NaiveDate> {
+    let (year, day) = text.split_once('-')?;
+    if year.len() != 4 || day.len() != 3 || !day.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    NaiveDate::from_yo_opt(year.parse().ok()?, day.parse().ok()?)
+}
+
+#[derive(Clone, Copy)]
+enum DateUnit {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// Words for relative date expressions in one language
+struct DateWords {
+    today: &'static [&'static str],
+    yesterday: &'static [&'static str],
+    tomorrow: &'static [&'static str],
+    last: &'static [&'static str],
+    next: &'static [&'static str],
+    /// Words around an amount meaning "in the past" ("3 days ago", "for 3 dager siden")
+    ago_prefix: &'static [&'static str],
+    ago_suffix: &'static [&'static str],
+    /// Word before an amount meaning "in the future" ("in 3 days", "om 3 dager")
+    in_prefix: &'static [&'static str],
+    /// Monday first
+    weekdays: [&'static [&'static str]; 7],
+    units: &'static [(&'static str, DateUnit)],
+}
+
+const ENGLISH_DATE_WORDS: DateWords = DateWords {
+    today: &["today"],
+    yesterday: &["yesterday"],
+    tomorrow: &["tomorrow"],
+    last: &["last"],
+    next: &["next"],
+    ago_prefix: &[],
+    ago_suffix: &["ago"],
+    in_prefix: &["in"],
+    weekdays: [
+        &["monday", "mon"],
+        &["tuesday", "tue", "tues"],
+        &["wednesday", "wed"],
+        &["thursday", "thu", "thurs"],
+        &["friday", "fri"],
+        &["saturday", "sat"],
+        &["sunday", "sun"],
+    ],
+    units: &[
+        ("d", DateUnit::Day), ("day", DateUnit::Day), ("days", DateUnit::Day),
+        ("w", DateUnit::Week), ("week", DateUnit::Week), ("weeks", DateUnit::Week),
+        ("month", DateUnit::Month), ("months", DateUnit::Month),
+        ("y", DateUnit::Year), ("year", DateUnit::Year), ("years", DateUnit::Year),
+    ],
+};
+
+const NORWEGIAN_DATE_WORDS: DateWords = DateWords {
+    today: &["i dag", "idag"],
+    yesterday: &["i går", "igår", "i gar"],
+    tomorrow: &["i morgen", "imorgen"],
+    last: &["forrige", "sist"],
+    next: &["neste", "kommende"],
+    ago_prefix: &["for"],
+    ago_suffix: &["siden"],
+    in_prefix: &["om"],
+    weekdays: [
+        &["mandag", "man"],
+        &["tirsdag", "tir"],
+        &["onsdag", "ons"],
+        &["torsdag", "tor"],
+        &["fredag", "fre"],
+        &["lørdag", "lør"],
+        &["søndag", "søn"],
+    ],
+    units: &[
+        ("d", DateUnit::Day), ("dag", DateUnit::Day), ("dager", DateUnit::Day),
+        ("u", DateUnit::Week), ("uke", DateUnit::Week), ("uker", DateUnit::Week),
+        ("mnd", DateUnit::Month), ("måned", DateUnit::Month), ("måneder", DateUnit::Month),
+        ("år", DateUnit::Year),
+    ],
+};
+
+impl DateWords {
+    fn parse(&self, text: &str, today: NaiveDate) -> Option<NaiveDate> {
+        if self.today.contains(&text) {
+            return Some(today);
+        }
+        if self.yesterday.contains(&text) {
+            return Some(today - Duration::days(1));
+        }
+        if self.tomorrow.contains(&text) {
+            return Some(today + Duration::days(1));
+        }
+
+        // "last friday" / "next monday": the nearest such day strictly before / after today
+        if let Some((word, day)) = text.split_once(' ') {
+            if let Some(weekday) = self.weekday(day) {
+                let from_monday = |d: Weekday| d.num_days_from_monday() as i64;
+                if self.last.contains(&word) {
+                    let back = (from_monday(today.weekday()) - from_monday(weekday)).rem_euclid(7);
+                    return Some(today - Duration::days(if back == 0 { 7 } else { back }));
+                }
+                if self.next.contains(&word) {
+                    let ahead = (from_monday(weekday) - from_monday(today.weekday())).rem_euclid(7);
+                    return Some(today + Duration::days(if ahead == 0 { 7 } else { ahead }));
+                }
+            }
+        }
+
+        // "3 days ago" / "for 3 dager siden" / "in 2 weeks"
+        if let Some(amount) = self.ago_suffix.iter().find_map(|suffix| text.strip_suffix(suffix)) {
+            let amount = amount.trim_end();
+            let amount = self.ago_prefix.iter().find_map(|prefix| amount.strip_prefix(&format!("{} ", prefix))).unwrap_or(amount);
+            let (count, unit) = self.amount(amount)?;
+            return shift_date(today, -count, unit);
+        }
+        if let Some(amount) = self.in_prefix.iter().find_map(|prefix| text.strip_prefix(&format!("{} ", prefix))) {
+            let (count, unit) = self.amount(amount)?;
+            return shift_date(today, count, unit);
+        }
+        None
+    }
+
+    fn weekday(&self, word: &str) -> Option<Weekday> {
+        let index = self.weekdays.iter().position(|names| names.contains(&word))?;
+        Weekday::try_from(index as u8).ok()
+    }
+
+    /// "3 days", "3days" or "2w"
+    fn amount(&self, text: &str) -> Option<(i64, DateUnit)> {
+        let digits = text.find(|c: char| !c.is_ascii_digit())?;
+        let count = text[..digits].parse().ok()?;
+        let unit = text[digits..].trim();
+        self.units.iter().find(|(name, _)| *name == unit).map(|(_, unit)| (count, *unit))
+    }
+}
+
+fn shift_date(date: NaiveDate, count: i64, unit: DateUnit) -> Option<NaiveDate> {
+    match unit {
+        DateUnit::Day => date.checked_add_signed(Duration::try_days(count)?),
+        DateUnit::Week => date.checked_add_signed(Duration::try_weeks(count)?),
+        DateUnit::Month | DateUnit::Year => {
+            let per_unit = if matches!(unit, DateUnit::Year) { 12 } else { 1 };
+            let months = Months::new(u32::try_from(count.unsigned_abs()).ok()?.checked_mul(per_unit)?);
+            if count < 0 { date.checked_sub_months(months) } else { date.checked_add_months(months) }
+        }
+    }
+}
+
+/// Inclusive range of calendar dates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl DateRange {
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Result<Self, JourneyError> {
+        if start > end {
+            return Err(JourneyError::InvalidDateFormat(format!(
+                "Invalid date range: {} is after {}", start, end
+            )));
+        }
+        Ok(Self { start, end })
+    }
+
+    /// A range covering a single day
+    pub fn single(date: NaiveDate) -> Self {
+        Self { start: date, end: date }
+    }
+
+    /// The seven-day week starting on `first_day` that contains the date
+    pub fn week_of(date: NaiveDate, first_day: Weekday) -> Self {
+        let start = date - Duration::days(date.weekday().days_since(first_day) as i64);
+        Self { start, end: start + Duration::days(6) }
+    }
+
+    /// The calendar month containing the date
+    pub fn month_of(date: NaiveDate) -> Self {
+        let start = date.with_day(1).unwrap_or(date);
+        let next_month = if start.month() == 12 {
+            NaiveDate::from_ymd_opt(start.year() + 1, 1, 1)
+        } else {
+            NaiveDate::from_ymd_opt(start.year(), start.month() + 1, 1)
+        };
+        let end = next_month.map(|d| d - Duration::days(1)).unwrap_or(date);
+        Self { start, end }
+    }
+
+    /// The last `days` days, ending with (and including) `end`
+    pub fn last_days(end: NaiveDate, days: u32) -> Self {
+        let start = end - Duration::days(days.saturating_sub(1) as i64);
+        Self { start, end }
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// Iterate over every day in the range, in order
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> {
+        let end = self.end;
+        self.start.iter_days().take_while(move |d| *d <= end)
+    }
+}
+
+/// The day whose ISO week numbers a week starting on `start`: its fourth day, so
+/// a week starting on Sunday takes the number of the Monday after it
+pub fn week_number_day(start: NaiveDate) -> NaiveDate {
+    start + Duration::days(3)
+}
+
+/// Span covered by a periodic (weekly, monthly, quarterly or yearly) note
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Period {
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Period {
+    /// The days covered by the period containing the date; weeks start on `first_day`
+    pub fn range(self, date: NaiveDate, first_day: Weekday) -> DateRange {
+        match self {
+            Period::Week => DateRange::week_of(date, first_day),
+            Period::Month => DateRange::month_of(date),
+            Period::Quarter => {
+                let first_month = (date.month() - 1) / 3 * 3 + 1;
+                let start = NaiveDate::from_ymd_opt(date.year(), first_month, 1).unwrap_or(date);
+                let end = DateRange::month_of(NaiveDate::from_ymd_opt(date.year(), first_month + 2, 1).unwrap_or(date)).end;
+                DateRange { start, end }
+            }
+            Period::Year => {
+                let start = NaiveDate::from_ymd_opt(date.year(), 1, 1).unwrap_or(date);
+                let end = NaiveDate::from_ymd_opt(date.year(), 12, 31).unwrap_or(date);
+                DateRange { start, end }
+            }
+        }
+    }
+
+    /// Short name of the period containing the date ("2025-W43", "2025-10", "2025-Q4", "2025")
+    pub fn label(self, date: NaiveDate, first_day: Weekday) -> String {
+        match self {
+            Period::Week => {
+                let week = week_number_day(self.range(date, first_day).start).iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+            Period::Month => format!("{}-{:02}", date.year(), date.month()),
+            Period::Quarter => format!("{}-Q{}", date.year(), (date.month() - 1) / 3 + 1),
+            Period::Year => date.year().to_string(),
+        }
+    }
+
+    /// File path format used when the vault does not configure one
+    pub fn default_path_format(self) -> &'static str {
+        match self {
+            Period::Week => "{week_year}-W{week:02}.md",
+            Period::Month => "{year}-{month:02}.md",
+            Period::Quarter => "{year}-Q{quarter}.md",
+            Period::Year => "{year}.md",
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::date_time::{DateRange, Period};
+use crate::errors::JourneyError;
+use crate::note::{Note, TaskStatus};
+use crate::vault::Vault;
+use chrono::NaiveDate;
+use std::collections::BTreeSet;
+
+/// Heading of the digest section, also used to find it again in a periodic note
+pub const DIGEST_HEADING: &str = "Digest";
+
+/// Notes of one category (or one unconfigured section) within the digest period
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigestGroup {
+    /// Section header text, or the section heading for notes outside configured categories
+    pub title: String,
+    /// Category name when the group comes from a configured section
+    pub category: Option<String>,
+    pub notes: Vec<Note>,
+}
+
+/// Every note of a week or month, grouped by category and then by day
+#[derive(Debug, Clone, PartialEq)]
+pub struct Digest {
+    pub label: String,
+    pub range: DateRange,
+    pub groups: Vec<DigestGroup>,
+}
+
+impl Digest {
+    /// Collect the daily notes of the period containing `date`
+    ///
+    /// Configured categories come first (in their configured order), followed by
+    /// other sections in order of appearance and finally notes outside any section.
+    pub fn build(vault: &Vault, period: Period, date: NaiveDate) -> Result<Self, JourneyError> {
+        let range = period.range(date, vault.config.week_starts_on());
+        let mut groups: Vec<DigestGroup> = vault.config.categories().into_iter()
+            .map(|(category, section)| DigestGroup {
+                title: section.header.trim_start_matches('#').trim().to_string(),
+                category: Some(category.to_string()),
+                notes: Vec::new(),
+            })
+            .collect();
+
+        let mut unsectioned = Vec::new();
+        for note in vault.collect_notes(Some(range), None)? {
+            let Some(section) = note.section.clone() else {
+                unsectioned.push(note);
+                continue;
+            };
+            let category = vault.config.category_for_section(&section);
+
+            let index = match groups.iter().position(|g| match category {
+                Some(category) => g.category.as_deref() == Some(category),
+                None => g.category.is_none() && g.title == section,
+            }) {
+                Some(index) => index,
+                None => {
+                    groups.push(DigestGroup { title: section, category: None, notes: Vec::new() });
+                    groups.len() - 1
+                }
+            };
+            groups[index].notes.push(note);
+        }
+
+        groups.retain(|g| !g.notes.is_empty());
+        if !unsectioned.is_empty() {
+            groups.push(DigestGroup { title: "Other".to_string(), category: None, notes: unsectioned });
+        }
+
+        Ok(Self { label: period.label(date, vault.config.week_starts_on()), range, groups })
+    }
+
+    pub fn note_count(&self) -> usize {
+        self.groups.iter().map(|g| g.notes.len()).sum()
+    }
+
+    /// Number of distinct days with at least one note
+    pub fn day_count(&self) -> usize {
+        self.groups.iter().flat_map(|g| g.notes.iter().map(|n| n.date)).collect::<BTreeSet<_>>().len()
+    }
+
+    /// Render as markdown under a single `# Digest` heading
+    pub fn to_markdown(&self, vault: &Vault) -> String {
+        let format_date = |date: NaiveDate| vault.date_handler.format_date(date);
+        let mut out = vec![
+            format!("# {} {}", DIGEST_HEADING, self.label),
+            String::new(),
+            format!("{} to {}: {} notes on {} days", format_date(self.range.start), format_date(self.range.end), self.note_count(), self.day_count()),
+        ];
+
+        if self.groups.is_empty() {
+            return out.join("\n") + "\n";
+        }
+
+        out.push(String::new());
+        out.push("| Category | Notes |".to_string());
+        out.push("|----------|-------|".to_string());
+        for group in &self.groups {
+            out.push(format!("| {} | {} |", group.title, group.notes.len()));
+        }
+
+        for group in &self.groups {
+            out.push(String::new());
+            out.push(format!("## {} ({})", group.title, group.notes.len()));
+
+            let mut day = None;
+            for note in &group.notes {
+                if day != Some(note.date) {
+                    day = Some(note.date);
+                    out.push(String::new());
+                    out.push(format!("### {} {}", format_date(note.date), note.date.format("%A")));
+                    out.push(String::new());
+                }
+                out.push(digest_line(note));
+            }
+        }
+
+        out.join("\n") + "\n"
+    }
+}
+
+/// One note as a markdown list item, continuation lines indented under it
+fn digest_line(note: &Note) -> String {
+    let checkbox = match note.task {
+        Some(TaskStatus::Open) => "[ ] ",
+        Some(TaskStatus::Done) => "[x] ",
+        None => "",
+    };
+    let time = note.time().map(|t| format!("{} ", t.format("%H:%M"))).unwrap_or_default();
+    let mut lines = note.content.lines();
+    let mut line = format!("- {}{}{}", checkbox, time, lines.next().unwrap_or_default());
+    for rest in lines {
+        line.push('\n');
+        if !rest.is_empty() {
+            line.push_str("  ");
+            line.push_str(rest);
+        }
+    }
+    line
+}
@@ -14,6 +14,9 @@ pub enum JourneyError {
     #[error("JSON parsing error: {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+
     #[error("Date/time parsing error: {0}")]
     DateTime(#[from] chrono::ParseError),
 
@@ -26,7 +29,25 @@ pub enum JourneyError {
     #[error("Invalid time format: {0}")]
     InvalidTimeFormat(String),
 
+    #[error("Nonexistent local time: {0}")]
+    NonexistentTime(String),
+
     #[error("Editor not found: {0}")]
     EditorNotFound(String),
+
+    #[error("Invalid search pattern: {0}")]
+    InvalidPattern(String),
+
+    #[error("Note not found: {0}")]
+    NoteNotFound(String),
+
+    #[error("Ambiguous note selection: {0}")]
+    AmbiguousNote(String),
+
+    #[error("Unknown category: {0}")]
+    UnknownCategory(String),
+
+    #[error("Not an open task: {0}")]
+    NotOpenTask(String),
 }
 
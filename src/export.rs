@@ -0,0 +1,79 @@
+use crate::errors::JourneyError;
+use crate::note::Note;
+use crate::vault::Vault;
+use chrono::{SecondsFormat, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+/// Output format for `journey export`
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// A single JSON array
+    Json,
+    /// One JSON object per line
+    Ndjson,
+    /// Comma-separated values with a header row
+    Csv,
+    /// iCalendar events, one per note
+    Ics,
+}
+
+/// One exported note; identical regardless of the vault's list format
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NoteRecord {
+    /// Date of the daily file (YYYY-MM-DD)
+    pub date: String,
+    /// Time of day (HH:MM:SS), if the note has one
+    pub time: Option<String>,
+    /// ISO 8601 timestamp with the note's UTC offset (recorded, or the vault zone's), if the note has a time
+    pub timestamp: Option<String>,
+    pub vault: String,
+    /// Heading the note lives under, if any
+    pub section: Option<String>,
+    pub content: String,
+}
+
+impl NoteRecord {
+    pub fn from_note(vault: &Vault, note: &Note) -> Self {
+        let time = note.time();
+        Self {
+            date: note.date.format("%Y-%m-%d").to_string(),
+            time: time.map(|t| t.format("%H:%M:%S").to_string()),
+            // A recorded offset wins over the vault's zone
+            timestamp: note.datetime().map(|datetime| {
+                note.offset()
+                    .and_then(|offset| offset.from_local_datetime(&datetime).single())
+                    .unwrap_or_else(|| vault.date_handler.combine_date_time(datetime.date(), datetime.time()))
+                    .to_rfc3339_opts(SecondsFormat::Secs, false)
+            }),
+            vault: vault.config.name.clone(),
+            section: note.section.clone(),
+            content: note.content.clone(),
+        }
+    }
+}
+
+/// Write records in the requested format
+pub fn write_records<W: Write>(records: &[NoteRecord], format: ExportFormat, mut out: W) -> Result<(), JourneyError> {
+    match format {
+        ExportFormat::Json => {
+            serde_json::to_writer_pretty(&mut out, records)?;
+            writeln!(out)?;
+        }
+        ExportFormat::Ndjson => {
+            for record in records {
+                serde_json::to_writer(&mut out, record)?;
+                writeln!(out)?;
+            }
+        }
+        ExportFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            for record in records {
+                writer.serialize(record)?;
+            }
+            writer.flush()?;
+        }
+        ExportFormat::Ics => crate::ics::write_calendar(records, None, out)?,
+    }
+    Ok(())
+}
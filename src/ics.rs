@@ -0,0 +1,372 @@
+use crate::date_time::Zone;
+use crate::errors::JourneyError;
+use crate::export::NoteRecord;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+use std::collections::HashMap;
+use std::io::Write;
+
+/// Write records as an iCalendar (RFC 5545) calendar with one VEVENT per note
+///
+/// Timed notes start at their timestamp and last `duration` (zero when None);
+/// notes without a time become all-day events. UIDs depend only on the vault,
+/// date, time and section, so re-exporting produces the same UIDs and calendar
+/// apps update existing events instead of duplicating them.
+pub fn write_calendar<W: Write>(records: &[NoteRecord], duration: Option<Duration>, mut out: W) -> Result<(), JourneyError> {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//journey//journey-cli//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+
+    // Notes sharing a timestamp are told apart by their order of appearance
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    for record in records {
+        let key = format!("{}|{}|{}|{}", record.vault, record.date, record.time.as_deref().unwrap_or(""), record.section.as_deref().unwrap_or(""));
+        let occurrence = seen.entry(key.clone()).or_insert(0);
+        *occurrence += 1;
+        let uid_key = if *occurrence == 1 { key } else { format!("{}|{}", key, occurrence) };
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{:016x}@journey", fnv1a(&uid_key)));
+
+        match record.timestamp.as_deref().map(DateTime::parse_from_rfc3339) {
+            Some(Ok(start)) => {
+                let start = start.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ").to_string();
+                lines.push(format!("DTSTAMP:{}", start));
+                lines.push(format!("DTSTART:{}", start));
+                if let Some(duration) = duration {
+                    lines.push(format!("DURATION:PT{}M", duration.num_minutes()));
+                }
+            }
+            _ => {
+                let date = NaiveDate::parse_from_str(&record.date, "%Y-%m-%d")
+                    .map_err(|_| JourneyError::InvalidDateFormat(record.date.clone()))?;
+                lines.push(format!("DTSTAMP:{}T000000Z", date.format("%Y%m%d")));
+                lines.push(format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")));
+                lines.push(format!("DTEND;VALUE=DATE:{}", (date + Duration::days(1)).format("%Y%m%d")));
+            }
+        }
+
+        let summary = record.content.lines().next().unwrap_or("");
+        lines.push(format!("SUMMARY:{}", escape_text(summary)));
+        lines.push(format!("DESCRIPTION:{}", escape_text(&record.content)));
+        if let Some(section) = &record.section {
+            lines.push(format!("CATEGORIES:{}", escape_text(section)));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    for line in lines {
+        out.write_all(fold_line(&line).as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Escape TEXT property values (backslash, separators and newlines)
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line at 75 octets and terminate it with CRLF
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+/// 64-bit FNV-1a; unlike std's hasher its output is stable across Rust releases
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+/// A VEVENT read from an iCalendar file
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEvent {
+    pub uid: Option<String>,
+    pub summary: String,
+    /// Start as wall-clock time in the zone given to `parse_calendar` (UTC and TZID
+    /// times are converted; floating times are taken as they are)
+    pub start: NaiveDateTime,
+    pub all_day: bool,
+    pub rrule: Option<String>,
+    pub exdates: Vec<NaiveDate>,
+    /// Set on instances that override one occurrence of a recurring event
+    pub recurrence_id: Option<NaiveDate>,
+    pub cancelled: bool,
+    /// The zone given to `parse_calendar`, used for the RRULE's UNTIL
+    pub zone: Zone,
+}
+
+/// Parse every VEVENT in an iCalendar document, with times in `zone`; events without a usable DTSTART are skipped
+pub fn parse_calendar(text: &str, zone: Zone) -> Vec<CalendarEvent> {
+    let mut events = Vec::new();
+    let mut current: Option<Vec<(String, String)>> = None;
+
+    for line in unfold_lines(text) {
+        match line.as_str() {
+            "BEGIN:VEVENT" => current = Some(Vec::new()),
+            "END:VEVENT" => {
+                if let Some(event) = current.take().and_then(|props| event_from_properties(&props, zone)) {
+                    events.push(event);
+                }
+            }
+            _ => {
+                if let (Some(props), Some((name, value))) = (current.as_mut(), line.split_once(':')) {
+                    props.push((name.to_string(), value.to_string()));
+                }
+            }
+        }
+    }
+
+    events
+}
+
+/// Events occurring on a date as (local start, summary), sorted by start time
+///
+/// Recurring events are expanded with their RRULE; overridden and cancelled
+/// occurrences are left out, as are all-day events, which have no start time.
+pub fn events_on(events: &[CalendarEvent], date: NaiveDate) -> Vec<(NaiveDateTime, String)> {
+    let mut found: Vec<(NaiveDateTime, String)> = Vec::new();
+
+    for event in events.iter().filter(|e| !e.cancelled && !e.all_day) {
+        if event.recurrence_id.is_some() {
+            if event.start.date() == date {
+                found.push((event.start, event.summary.clone()));
+            }
+            continue;
+        }
+
+        let overridden = events.iter().any(|other| {
+            other.recurrence_id == Some(date) && other.uid.is_some() && other.uid == event.uid
+        });
+        if !overridden && occurs_on(event, date) {
+            found.push((date.and_time(event.start.time()), event.summary.clone()));
+        }
+    }
+
+    found.sort();
+    found
+}
+
+/// Whether an event (or one of its recurrences) falls on a date
+fn occurs_on(event: &CalendarEvent, date: NaiveDate) -> bool {
+    let first = event.start.date();
+    if date < first || event.exdates.contains(&date) {
+        return false;
+    }
+    if date == first {
+        return true;
+    }
+    let Some(rule) = event.rrule.as_deref().and_then(|rrule| RecurrenceRule::parse(rrule, event.zone)) else {
+        return false;
+    };
+    if rule.until.is_some_and(|until| date.and_time(event.start.time()) > until) || !rule.matches(first, date) {
+        return false;
+    }
+    match rule.count {
+        // DTSTART is the first occurrence, and excluded dates still count towards COUNT
+        Some(count) => {
+            let seen = 1 + first.iter_days()
+                .skip(1)
+                .take_while(|d| *d <= date)
+                .filter(|d| rule.matches(first, *d))
+                .count();
+            seen <= count
+        }
+        None => true,
+    }
+}
+
+/// The subset of RRULE journey understands: FREQ, INTERVAL, COUNT, UNTIL and weekly BYDAY
+struct RecurrenceRule {
+    freq: Frequency,
+    interval: i64,
+    count: Option<usize>,
+    /// Last possible start, as wall-clock time in the event's zone
+    until: Option<NaiveDateTime>,
+    by_day: Vec<Weekday>,
+}
+
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl RecurrenceRule {
+    /// Parse an RRULE value, reading UNTIL into `zone`; rules using parts beyond the supported subset yield None
+    fn parse(value: &str, zone: Zone) -> Option<Self> {
+        let mut rule = RecurrenceRule { freq: Frequency::Daily, interval: 1, count: None, until: None, by_day: Vec::new() };
+        let mut has_freq = false;
+
+        for part in value.split(';') {
+            let (key, val) = part.split_once('=')?;
+            match key {
+                "FREQ" => {
+                    rule.freq = match val {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return None,
+                    };
+                    has_freq = true;
+                }
+                "INTERVAL" => rule.interval = val.parse().ok().filter(|i| *i > 0)?,
+                "COUNT" => rule.count = Some(val.parse().ok()?),
+                "UNTIL" => {
+                    // A plain date includes the whole day
+                    let (until, date_only) = parse_ics_datetime(val, "UNTIL", zone)?;
+                    rule.until = Some(if date_only { until.date().and_hms_opt(23, 59, 59)? } else { until });
+                }
+                "BYDAY" => {
+                    rule.by_day = val.split(',').map(parse_weekday).collect::<Option<Vec<_>>>()?;
+                }
+                "WKST" => {}
+                _ => return None,
+            }
+        }
+
+        // BYDAY is only understood for weekly rules
+        if !rule.by_day.is_empty() && !matches!(rule.freq, Frequency::Weekly) {
+            return None;
+        }
+        has_freq.then_some(rule)
+    }
+
+    /// Whether the pattern (ignoring COUNT and UNTIL) produces an occurrence on `date`
+    fn matches(&self, first: NaiveDate, date: NaiveDate) -> bool {
+        match self.freq {
+            Frequency::Daily => (date - first).num_days() % self.interval == 0,
+            Frequency::Weekly => {
+                let week_start = |d: NaiveDate| d - Duration::days(d.weekday().num_days_from_monday() as i64);
+                let weeks = (week_start(date) - week_start(first)).num_days() / 7;
+                let on_day = if self.by_day.is_empty() {
+                    date.weekday() == first.weekday()
+                } else {
+                    self.by_day.contains(&date.weekday())
+                };
+                on_day && weeks % self.interval == 0
+            }
+            Frequency::Monthly => {
+                let months = (date.year() - first.year()) as i64 * 12 + date.month() as i64 - first.month() as i64;
+                date.day() == first.day() && months % self.interval == 0
+            }
+            Frequency::Yearly => {
+                date.month() == first.month()
+                    && date.day() == first.day()
+                    && (date.year() - first.year()) as i64 % self.interval == 0
+            }
+        }
+    }
+}
+
+fn parse_weekday(code: &str) -> Option<Weekday> {
+    match code {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn event_from_properties(props: &[(String, String)], zone: Zone) -> Option<CalendarEvent> {
+    // Property names may carry parameters, e.g. "DTSTART;TZID=Europe/Oslo"
+    let find = |name: &str| props.iter().find(|(key, _)| key.split(';').next() == Some(name));
+
+    let (start_key, start_value) = find("DTSTART")?;
+    let (start, all_day) = parse_ics_datetime(start_value, start_key, zone)?;
+
+    let exdates = props.iter()
+        .filter(|(key, _)| key.split(';').next() == Some("EXDATE"))
+        .flat_map(|(key, value)| value.split(',').filter_map(|v| parse_ics_datetime(v, key, zone)).collect::<Vec<_>>())
+        .map(|(dt, _)| dt.date())
+        .collect();
+
+    Some(CalendarEvent {
+        uid: find("UID").map(|(_, v)| v.clone()),
+        summary: find("SUMMARY").map(|(_, v)| unescape_text(v)).unwrap_or_default(),
+        start,
+        all_day,
+        rrule: find("RRULE").map(|(_, v)| v.clone()),
+        exdates,
+        recurrence_id: find("RECURRENCE-ID").and_then(|(key, v)| parse_ics_datetime(v, key, zone)).map(|(dt, _)| dt.date()),
+        cancelled: find("STATUS").is_some_and(|(_, v)| v == "CANCELLED"),
+        zone,
+    })
+}
+
+/// Parse a DATE or DATE-TIME value into wall-clock time in `zone`, reporting whether it was a plain date
+///
+/// `key` is the full property name with its parameters ("DTSTART;TZID=Europe/Oslo").
+fn parse_ics_datetime(value: &str, key: &str, zone: Zone) -> Option<(NaiveDateTime, bool)> {
+    let value = value.trim();
+    if key.contains("VALUE=DATE") || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Some((date.and_time(NaiveTime::MIN), true));
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some((zone.from_utc(naive.and_utc()).naive_local(), false));
+    }
+
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+    let source = key.split(';').find_map(|param| param.strip_prefix("TZID=")).and_then(|tzid| tzid.trim_matches('"').parse::<Zone>().ok());
+    match source.and_then(|source| source.from_local(naive).earliest()) {
+        Some(start) => Some((zone.from_utc(start.to_utc()).naive_local(), false)),
+        // Floating time, or a TZID we don't know
+        None => Some((naive, false)),
+    }
+}
+
+/// Undo TEXT escaping
+fn unescape_text(text: &str) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') | Some('N') => result.push('\n'),
+                Some(other) => result.push(other),
+                None => {}
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Join folded content lines and drop line endings
+fn unfold_lines(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let line = line.trim_end_matches('\r');
+        match (line.strip_prefix(' ').or_else(|| line.strip_prefix('\t')), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
@@ -0,0 +1,224 @@
+use crate::date_time::{TimeExpr, Zone};
+use crate::errors::JourneyError;
+use crate::note::{has_tag, multiline_content};
+use crate::vault::{BatchOutcome, PendingNote, Vault};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{BufRead, Read};
+use std::path::{Path, PathBuf};
+
+/// Input format for `journey import`
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// One JSON object per line
+    Ndjson,
+}
+
+/// One entry to import; extra fields (such as those written by `journey export`) are ignored
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ImportRecord {
+    pub date: String,
+    pub time: String,
+    pub content: String,
+    #[serde(default)]
+    pub category: Option<String>,
+}
+
+/// Per-file results of an import
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportSummary {
+    pub files: Vec<BatchOutcome>,
+}
+
+impl ImportSummary {
+    pub fn added(&self) -> usize {
+        self.files.iter().map(|f| f.added).sum()
+    }
+
+    pub fn skipped(&self) -> usize {
+        self.files.iter().map(|f| f.skipped).sum()
+    }
+}
+
+/// Read every record from CSV or NDJSON input
+pub fn read_records<R: Read>(reader: R, format: ImportFormat) -> Result<Vec<ImportRecord>, JourneyError> {
+    match format {
+        ImportFormat::Csv => {
+            let mut csv = csv::ReaderBuilder::new().trim(csv::Trim::Headers).from_reader(reader);
+            csv.deserialize().map(|record| record.map_err(JourneyError::from)).collect()
+        }
+        ImportFormat::Ndjson => {
+            let mut records = Vec::new();
+            for line in std::io::BufReader::new(reader).lines() {
+                let line = line?;
+                if !line.trim().is_empty() {
+                    records.push(serde_json::from_str(&line)?);
+                }
+            }
+            Ok(records)
+        }
+    }
+}
+
+/// Import records into a vault, writing each daily file once
+pub fn import_records(vault: &Vault, records: &[ImportRecord]) -> Result<ImportSummary, JourneyError> {
+    let mut notes = Vec::new();
+
+    for (i, record) in records.iter().enumerate() {
+        let date = vault.date_handler.parse_date(record.date.trim())
+            .map_err(|_| JourneyError::InvalidDateFormat(format!("record {}: '{}'", i + 1, record.date)))?;
+        let time = vault.date_handler.parse_time(record.time.trim())
+            .map_err(|_| JourneyError::InvalidTimeFormat(format!("record {}: '{}'", i + 1, record.time)))?;
+
+        notes.push(PendingNote {
+            content: record.content.clone(),
+            // Times before the vault's day start fall after midnight of the record's day
+            timestamp: {
+                let datetime = vault.date_handler.resolve_time_expression(TimeExpr::At(time), date);
+                vault.date_handler.combine_date_time(datetime.date(), datetime.time())
+            },
+            category: record.category.clone().filter(|c| !c.is_empty()),
+        });
+    }
+
+    import_notes(vault, notes)
+}
+
+/// Write notes into their daily files, one write per day
+///
+/// Every category is checked before anything is written, so an unknown one
+/// leaves the vault untouched.
+pub fn import_notes(vault: &Vault, notes: Vec<PendingNote>) -> Result<ImportSummary, JourneyError> {
+    let categories: BTreeSet<&str> = notes.iter().filter_map(|note| note.category.as_deref()).collect();
+    for category in categories {
+        vault.config.find_category(category)?;
+    }
+
+    let mut days: BTreeMap<NaiveDate, Vec<PendingNote>> = BTreeMap::new();
+    for note in notes {
+        days.entry(vault.date_handler.logical_date(note.timestamp.naive_local())).or_default().push(note);
+    }
+
+    let mut summary = ImportSummary::default();
+    for (date, notes) in days {
+        summary.files.push(vault.add_notes(date, &notes)?);
+    }
+    Ok(summary)
+}
+
+/// Journal applications `journeyctl import` can migrate from
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForeignJournal {
+    /// jrnl plain-text export ("[YYYY-MM-DD HH:MM] title" entries)
+    Jrnl,
+    /// Day One JSON export (a Journal.json file or the folder containing it)
+    Dayone,
+}
+
+/// Read every entry from a jrnl or Day One export, with times in `zone`
+pub fn read_foreign(path: &Path, source: ForeignJournal, zone: Zone) -> Result<Vec<PendingNote>, JourneyError> {
+    match source {
+        ForeignJournal::Jrnl => parse_jrnl(&fs::read_to_string(path)?, zone),
+        ForeignJournal::Dayone if path.is_dir() => {
+            let mut files: Vec<PathBuf> = fs::read_dir(path)?
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+                .collect();
+            files.sort();
+
+            let mut notes = Vec::new();
+            for file in files {
+                notes.extend(parse_dayone(&fs::read_to_string(file)?, zone)?);
+            }
+            Ok(notes)
+        }
+        ForeignJournal::Dayone => parse_dayone(&fs::read_to_string(path)?, zone),
+    }
+}
+
+/// Parse a jrnl plain-text export; `@tags` become `#tags` and times are wall-clock times in `zone`
+pub fn parse_jrnl(text: &str, zone: Zone) -> Result<Vec<PendingNote>, JourneyError> {
+    let header = Regex::new(r"^\[(\d{4}-\d{2}-\d{2} \d{1,2}:\d{2}(?::\d{2})?(?: ?[AaPp][Mm])?)\] ?(.*)$").unwrap();
+    let tag = Regex::new(r"(^|\s)@([\w-]+)").unwrap();
+
+    let mut entries: Vec<(DateTime<FixedOffset>, Vec<&str>)> = Vec::new();
+    for line in text.lines() {
+        if let Some(caps) = header.captures(line) {
+            let stamp = &caps[1];
+            let naive = ["%Y-%m-%d %H:%M", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d %I:%M %p", "%Y-%m-%d %I:%M%p"]
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(stamp, format).ok())
+                .ok_or_else(|| JourneyError::InvalidDateFormat(stamp.to_string()))?;
+            let timestamp = zone.from_local(naive).earliest()
+                .ok_or_else(|| JourneyError::InvalidTimeFormat(stamp.to_string()))?;
+            entries.push((timestamp, vec![caps.get(2).map_or("", |m| m.as_str())]));
+        } else if let Some((_, lines)) = entries.last_mut() {
+            lines.push(line);
+        }
+    }
+
+    Ok(entries
+        .into_iter()
+        .map(|(timestamp, lines)| PendingNote {
+            content: tag.replace_all(&multiline_content(&lines.join("\n")), "$1#$2").into_owned(),
+            timestamp,
+            category: None,
+        })
+        .filter(|note| !note.content.is_empty())
+        .collect())
+}
+
+#[derive(Deserialize)]
+struct DayOneExport {
+    entries: Vec<DayOneEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DayOneEntry {
+    creation_date: String,
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// Parse a Day One JSON export; UTC creation dates are converted to `zone` and tags appended as hashtags
+pub fn parse_dayone(json: &str, zone: Zone) -> Result<Vec<PendingNote>, JourneyError> {
+    let export: DayOneExport = serde_json::from_str(json)?;
+    // Day One escapes markdown punctuation and references attachments by moment URL
+    let escape = Regex::new(r"\\([\\`*_{}\[\]()#+\-.!>|~])").unwrap();
+    let moment = Regex::new(r"!\[[^\]]*\]\(dayone-moment:[^)]*\)").unwrap();
+
+    let mut notes = Vec::new();
+    for entry in export.entries {
+        let timestamp = DateTime::parse_from_rfc3339(&entry.creation_date)
+            .map_err(|_| JourneyError::InvalidDateFormat(entry.creation_date.clone()))?;
+        let timestamp = zone.from_utc(timestamp.to_utc());
+
+        let text = moment.replace_all(&entry.text, "");
+        let text = escape.replace_all(&text, "$1");
+        let mut content = multiline_content(&text);
+
+        for tag in &entry.tags {
+            let tag = tag.trim().replace(char::is_whitespace, "-");
+            if !tag.is_empty() && !has_tag(&content, &tag) {
+                if !content.is_empty() {
+                    content.push(' ');
+                }
+                content.push('#');
+                content.push_str(&tag);
+            }
+        }
+
+        if !content.is_empty() {
+            notes.push(PendingNote { content, timestamp, category: None });
+        }
+    }
+    Ok(notes)
+}
@@ -1,3 +1,4 @@
+use crate::import::ForeignJournal;
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
@@ -59,4 +60,27 @@ pub enum Commands {
         #[arg(long)]
         verbose: bool,
     },
+    /// Import entries from another journal application
+    Import {
+        /// Journal application the export comes from
+        #[arg(long, value_enum)]
+        from: ForeignJournal,
+
+        /// Export file (or Day One export folder)
+        path: PathBuf,
+
+        /// Name of the vault to import into (uses default if not specified)
+        #[arg(short, long)]
+        vault: Option<String>,
+    },
+    /// Render a vault as a static HTML site
+    Publish {
+        /// Name of the vault to publish (uses default if not specified)
+        #[arg(short, long)]
+        vault: Option<String>,
+
+        /// Output directory
+        #[arg(short, long)]
+        out: PathBuf,
+    },
 }
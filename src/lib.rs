@@ -1,8 +1,19 @@
 pub mod app;
+pub mod calendar;
 pub mod cli;
 pub mod config;
 pub mod config_manager;
 pub mod date_time;
+pub mod digest;
 pub mod errors;
+pub mod export;
+pub mod ics;
+pub mod import;
 pub mod journeyctl;
+pub mod note;
+pub mod onthisday;
+pub mod publish;
+pub mod search;
+pub mod stats;
+pub mod tags;
 pub mod vault;
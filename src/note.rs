@@ -0,0 +1,414 @@
+use crate::config::NoteFormat;
+use crate::errors::JourneyError;
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use std::cmp::Ordering;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// A single journal entry read from (or destined for) a daily file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Note {
+    /// Date of the daily file the note belongs to
+    pub date: NaiveDate,
+    /// Raw timestamp text as written in the file (e.g. "14:30:00")
+    pub timestamp: Option<String>,
+    /// Note text without list or table markup
+    pub content: String,
+    /// Heading text of the section the note lives under, if any
+    pub section: Option<String>,
+    /// File the note was read from
+    pub source: Option<PathBuf>,
+    /// Zero-based line range of the note within its source file
+    pub line_span: Range<usize>,
+    /// Checkbox state when the note is a task
+    pub task: Option<TaskStatus>,
+}
+
+/// Checkbox state of a task note ("- [ ]" or "- [x]")
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskStatus {
+    Open,
+    Done,
+}
+
+impl TaskStatus {
+    fn checkbox(self) -> &'static str {
+        match self {
+            TaskStatus::Open => "[ ]",
+            TaskStatus::Done => "[x]",
+        }
+    }
+
+    /// Split a leading "[ ] " or "[x] " checkbox off the text
+    fn strip(text: &str) -> (Option<TaskStatus>, &str) {
+        let status = match text.get(..3) {
+            Some("[ ]") => TaskStatus::Open,
+            Some("[x]") | Some("[X]") => TaskStatus::Done,
+            _ => return (None, text),
+        };
+        match &text[3..] {
+            "" => (Some(status), ""),
+            rest if rest.starts_with(' ') => (Some(status), rest.trim_start()),
+            _ => (None, text),
+        }
+    }
+}
+
+impl Note {
+    pub fn new(date: NaiveDate, timestamp: Option<String>, content: &str) -> Self {
+        Self {
+            date,
+            timestamp,
+            content: content.to_string(),
+            section: None,
+            source: None,
+            line_span: 0..1,
+            task: None,
+        }
+    }
+
+    /// A new open task
+    pub fn task(date: NaiveDate, timestamp: Option<String>, content: &str) -> Self {
+        Self { task: Some(TaskStatus::Open), ..Self::new(date, timestamp, content) }
+    }
+
+    pub fn is_open_task(&self) -> bool {
+        self.task == Some(TaskStatus::Open)
+    }
+
+    /// Parse the timestamp into a time of day, if it holds one
+    pub fn time(&self) -> Option<NaiveTime> {
+        self.timestamp.as_deref().and_then(parse_timestamp)
+    }
+
+    /// Date and time of the note; a full "YYYY-MM-DD HH:MM:SS" timestamp carries its own date
+    pub fn datetime(&self) -> Option<NaiveDateTime> {
+        let (timestamp, _) = split_offset(self.timestamp.as_deref()?);
+        NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d %H:%M:%S")
+            .or_else(|_| NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d %H:%M"))
+            .ok()
+            .or_else(|| {
+                let (time, next_day) = parse_clock(timestamp)?;
+                Some(self.date.and_time(time) + chrono::Duration::days(next_day as i64))
+            })
+    }
+
+    /// UTC offset recorded after the time ("14:30:00 +02:00"), if any
+    pub fn offset(&self) -> Option<FixedOffset> {
+        split_offset(self.timestamp.as_deref()?).1
+    }
+
+    /// Inline hashtags in the note content, without the leading '#'
+    pub fn tags(&self) -> Vec<String> {
+        extract_tags(&self.content)
+    }
+
+    /// Whether the note carries the tag (case-insensitive, leading '#' optional)
+    pub fn has_tag(&self, tag: &str) -> bool {
+        has_tag(&self.content, tag)
+    }
+
+    /// Chronological ordering; notes without a time sort after timed ones
+    pub fn cmp_by_time(&self, other: &Note) -> Ordering {
+        match (self.datetime(), other.datetime()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+/// Parse a note timestamp ("HH:MM:SS", "HH:MM", "25:30:00" or "YYYY-MM-DD HH:MM:SS") into a time of day
+pub fn parse_timestamp(timestamp: &str) -> Option<NaiveTime> {
+    let (timestamp, _) = split_offset(timestamp);
+    if let Some((time, _)) = parse_clock(timestamp) {
+        return Some(time);
+    }
+    NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d %H:%M"))
+        .map(|dt| dt.time())
+        .ok()
+}
+
+/// A time of day, and whether it runs past 24:00 into the next day ("25:30:00" is 01:30 the next day)
+fn parse_clock(timestamp: &str) -> Option<(NaiveTime, bool)> {
+    let parse = |time: &str| NaiveTime::parse_from_str(time, "%H:%M:%S").or_else(|_| NaiveTime::parse_from_str(time, "%H:%M")).ok();
+    if let Some(time) = parse(timestamp) {
+        return Some((time, false));
+    }
+    let (hours, rest) = timestamp.split_once(':')?;
+    let hours: u32 = hours.parse().ok().filter(|hours| (24..48).contains(hours))?;
+    parse(&format!("{:02}:{}", hours - 24, rest)).map(|time| (time, true))
+}
+
+/// Split a trailing UTC offset ("+02:00") off a timestamp
+pub fn split_offset(timestamp: &str) -> (&str, Option<FixedOffset>) {
+    let timestamp = timestamp.trim();
+    if let Some((time, offset)) = timestamp.rsplit_once(' ') {
+        if offset.starts_with(['+', '-']) {
+            if let Ok(offset) = FixedOffset::from_str(offset) {
+                return (time.trim_end(), Some(offset));
+            }
+        }
+    }
+    (timestamp, None)
+}
+
+/// Extract inline `#tag` tokens from text
+///
+/// A tag starts at a '#' at the beginning of a word and runs over letters,
+/// digits, '_', '-' and '/'. Markdown headings ("# Title"), purely numeric
+/// references ("#42") and URL fragments ("https://x/page#anchor") are not tags.
+pub fn extract_tags(text: &str) -> Vec<String> {
+    let mut tags = Vec::new();
+
+    for word in text.split_whitespace() {
+        if word.contains("://") {
+            continue;
+        }
+        let Some(rest) = word.strip_prefix('#') else {
+            continue;
+        };
+        let tag: String = rest
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '/'))
+            .collect();
+        let tag = tag.trim_end_matches(['-', '/']);
+        if !tag.is_empty() && !tag.chars().all(|c| c.is_ascii_digit()) && !tags.iter().any(|t: &String| t == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+
+    tags
+}
+
+/// Whether text carries the tag (case-insensitive, leading '#' optional)
+pub fn has_tag(text: &str, tag: &str) -> bool {
+    let wanted = tag.trim_start_matches('#').to_lowercase();
+    extract_tags(text).iter().any(|t| t.to_lowercase() == wanted)
+}
+
+/// Normalize a multi-line note: strip trailing whitespace and surrounding blank lines
+pub fn multiline_content(input: &str) -> String {
+    let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+    let start = lines.iter().position(|line| !line.is_empty()).unwrap_or(lines.len());
+    let end = lines.iter().rposition(|line| !line.is_empty()).map_or(start, |i| i + 1);
+    lines[start..end].join("\n")
+}
+
+/// Identifies a single note within one day's listing
+#[derive(Debug, Clone, PartialEq)]
+pub enum NoteSelector {
+    /// The note at a time of day; without seconds any second within the minute matches
+    Time { time: NaiveTime, with_seconds: bool },
+    /// 1-based position in the `--list` output
+    Index(usize),
+    /// The latest note of the day
+    Last,
+}
+
+impl NoteSelector {
+    /// Pick exactly one note from a chronologically sorted listing
+    pub fn select<'a>(&self, notes: &'a [Note]) -> Result<&'a Note, JourneyError> {
+        match self {
+            NoteSelector::Time { time, with_seconds } => {
+                let matches: Vec<&Note> = notes.iter().filter(|note| {
+                    note.time().is_some_and(|t| {
+                        if *with_seconds {
+                            t == *time
+                        } else {
+                            t.hour() == time.hour() && t.minute() == time.minute()
+                        }
+                    })
+                }).collect();
+
+                match matches.as_slice() {
+                    [note] => Ok(note),
+                    [] => Err(JourneyError::NoteNotFound(format!("no note at {}", time.format("%H:%M")))),
+                    many => Err(JourneyError::AmbiguousNote(format!(
+                        "{} notes at {}: {}. Use --index to pick one",
+                        many.len(),
+                        time.format("%H:%M"),
+                        many.iter().map(|n| n.content.as_str()).collect::<Vec<_>>().join("; ")
+                    ))),
+                }
+            }
+            NoteSelector::Index(index) => index
+                .checked_sub(1)
+                .and_then(|i| notes.get(i))
+                .ok_or_else(|| JourneyError::NoteNotFound(format!("no note #{} ({} notes listed)", index, notes.len()))),
+            NoteSelector::Last => notes
+                .last()
+                .ok_or_else(|| JourneyError::NoteNotFound("no notes for this day".to_string())),
+        }
+    }
+}
+
+/// Reads and writes single note lines for one `NoteFormat`
+#[derive(Debug, Clone)]
+pub struct NoteCodec {
+    format: NoteFormat,
+    table_headers: (String, String),
+}
+
+impl NoteCodec {
+    pub fn new(format: NoteFormat, table_headers: (String, String)) -> Self {
+        Self { format, table_headers }
+    }
+
+    pub fn format(&self) -> &NoteFormat {
+        &self.format
+    }
+
+    /// Parse a line into a note, returning None if the line is not a note in this format
+    pub fn parse(&self, line: &str, date: NaiveDate) -> Option<Note> {
+        let (task, timestamp, content) = match self.format {
+            NoteFormat::Bullet => Self::parse_bullet(line)?,
+            NoteFormat::Table => {
+                if self.is_header_line(line) {
+                    return None;
+                }
+                Self::parse_table(line)?
+            }
+        };
+        Some(Note { task, ..Note::new(date, timestamp, &content) })
+    }
+
+    /// Parse a note starting at the first line, together with the continuation lines that follow it
+    ///
+    /// Returns the note (with `line_span` relative to `lines`). Bullet notes
+    /// continue over indented lines; table notes are always a single row.
+    pub fn parse_block(&self, lines: &[&str], date: NaiveDate) -> Option<Note> {
+        let mut note = self.parse(lines.first()?, date)?;
+
+        let continuation = match self.format {
+            NoteFormat::Bullet => continuation_len(&lines[1..]),
+            NoteFormat::Table => 0,
+        };
+        for line in &lines[1..=continuation] {
+            note.content.push('\n');
+            note.content.push_str(strip_indent(line).trim_end());
+        }
+        note.line_span = 0..continuation + 1;
+        Some(note)
+    }
+
+    /// Render a note (without trailing newline); multi-line content becomes
+    /// indented continuation lines in bullet mode and `<br>`-joined cells in table mode
+    pub fn render(&self, note: &Note) -> String {
+        let timestamp = note.timestamp.as_deref().unwrap_or("");
+        match self.format {
+            NoteFormat::Bullet => {
+                let checkbox = note.task.map(|t| format!("{} ", t.checkbox())).unwrap_or_default();
+                let body = note.content
+                    .lines()
+                    .enumerate()
+                    .map(|(i, line)| if i == 0 || line.is_empty() { line.to_string() } else { format!("{}{}", CONTINUATION_INDENT, line) })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                if timestamp.is_empty() {
+                    format!("- {}{}", checkbox, body)
+                } else {
+                    format!("- {}[{}] {}", checkbox, timestamp, body)
+                }
+            }
+            NoteFormat::Table => {
+                let checkbox = note.task.map(|t| format!("{} ", t.checkbox())).unwrap_or_default();
+                format!("| {} | {}{} |", timestamp, checkbox, note.content.lines().collect::<Vec<_>>().join("<br>"))
+            }
+        }
+    }
+
+    /// Lines that precede the first note of a block (table header and separator)
+    pub fn header_lines(&self) -> Vec<String> {
+        match self.format {
+            NoteFormat::Bullet => Vec::new(),
+            NoteFormat::Table => vec![
+                format!("| {} | {} |", self.table_headers.0, self.table_headers.1),
+                "|------|----------|".to_string(),
+            ],
+        }
+    }
+
+    /// Determine if a line is a table header or separator line
+    pub fn is_header_line(&self, line: &str) -> bool {
+        let trimmed = line.trim();
+
+        // Separator like |---|
+        if trimmed.starts_with("|---") {
+            return true;
+        }
+
+        // Match against localized/custom headers, and the English default written by older versions
+        if trimmed.starts_with('|') {
+            let expected = format!("| {} | {} |", self.table_headers.0, self.table_headers.1);
+            return trimmed == expected || trimmed == "| Time | Content |";
+        }
+
+        false
+    }
+
+    /// Format: "- [ ] [timestamp] content", "- [timestamp] content" or "- content"
+    fn parse_bullet(line: &str) -> Option<(Option<TaskStatus>, Option<String>, String)> {
+        let after_dash = line.trim().strip_prefix("- ")?;
+        let (task, after_dash) = TaskStatus::strip(after_dash);
+
+        // Only treat the bracket as a timestamp when it looks like one, so
+        // links ("- [docs](url)") stay part of the content
+        if let Some(after_bracket) = after_dash.strip_prefix('[') {
+            if let Some(closing) = after_bracket.find(']') {
+                let timestamp = &after_bracket[..closing];
+                if timestamp.starts_with(|c: char| c.is_ascii_digit()) {
+                    let content = after_bracket[closing + 1..].trim().to_string();
+                    return Some((task, Some(timestamp.to_string()), content));
+                }
+            }
+        }
+
+        Some((task, None, after_dash.trim().to_string()))
+    }
+
+    /// Format: "| timestamp | content |"; pipes inside the content are preserved
+    fn parse_table(line: &str) -> Option<(Option<TaskStatus>, Option<String>, String)> {
+        let inner = line.trim().strip_prefix('|')?;
+        let inner = inner.strip_suffix('|').unwrap_or(inner);
+        let (timestamp, content) = inner.split_once('|')?;
+
+        let timestamp = timestamp.trim();
+        let timestamp = if timestamp.is_empty() { None } else { Some(timestamp.to_string()) };
+        let (task, content) = TaskStatus::strip(content.trim());
+        Some((task, timestamp, content.replace("<br>", "\n")))
+    }
+}
+
+/// Indentation written before each continuation line of a multi-line bullet note
+const CONTINUATION_INDENT: &str = "  ";
+
+/// Whether a line is an indented continuation of the bullet note above it
+pub fn is_continuation(line: &str) -> bool {
+    (line.starts_with(' ') || line.starts_with('\t')) && !line.trim().is_empty()
+}
+
+/// Number of leading lines that continue a bullet note
+///
+/// Blank lines belong to the block only when more indented lines follow them,
+/// so paragraphs inside a note survive while the gap after it does not.
+fn continuation_len(lines: &[&str]) -> usize {
+    let mut len = 0;
+    for (i, line) in lines.iter().enumerate() {
+        if is_continuation(line) {
+            len = i + 1;
+        } else if !line.trim().is_empty() {
+            break;
+        }
+    }
+    len
+}
+
+fn strip_indent(line: &str) -> &str {
+    line.strip_prefix(CONTINUATION_INDENT)
+        .or_else(|| line.strip_prefix('\t'))
+        .unwrap_or_else(|| line.trim_start())
+}
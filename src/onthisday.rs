@@ -0,0 +1,31 @@
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// An earlier day to look back at
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lookback {
+    /// Heading for the day ("2024", "1 week ago", ...)
+    pub label: String,
+    pub date: NaiveDate,
+}
+
+/// The same month and day in another year; Feb 29 falls back to Feb 28 outside leap years
+pub fn same_day_in_year(date: NaiveDate, year: i32) -> Option<NaiveDate> {
+    date.with_year(year).or_else(|| NaiveDate::from_ymd_opt(year, date.month(), date.day() - 1))
+}
+
+/// Days to review for `date`: the same day in every year back to `earliest_year`
+/// (most recent first), optionally followed by the same weekday 1 week, 4 weeks
+/// (a month) and 13 weeks (a quarter) earlier
+pub fn lookback_days(date: NaiveDate, earliest_year: i32, recent: bool) -> Vec<Lookback> {
+    let mut days: Vec<Lookback> = (earliest_year..date.year())
+        .rev()
+        .filter_map(|year| same_day_in_year(date, year).map(|day| Lookback { label: year.to_string(), date: day }))
+        .collect();
+
+    if recent {
+        for (weeks, label) in [(1, "1 week ago"), (4, "1 month ago"), (13, "1 quarter ago")] {
+            days.push(Lookback { label: label.to_string(), date: date - Duration::weeks(weeks) });
+        }
+    }
+    days
+}
@@ -0,0 +1,281 @@
+use crate::errors::JourneyError;
+use crate::note::Note;
+use crate::vault::Vault;
+use chrono::{Datelike, NaiveDate};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const STYLESHEET: &str = "body { font-family: system-ui, sans-serif; max-width: 46rem; margin: 2rem auto; padding: 0 1rem; line-height: 1.5; color: #222; }
+nav { display: flex; gap: 1rem; justify-content: space-between; margin: 1rem 0; font-size: 0.9rem; }
+a { color: #2457a7; text-decoration: none; }
+a:hover { text-decoration: underline; }
+ul.notes { list-style: none; padding: 0; }
+ul.notes li { margin: 0.3rem 0; }
+a.time { font-family: ui-monospace, monospace; color: #777; margin-right: 0.5rem; }
+li.done { color: #777; text-decoration: line-through; }
+h1 small { color: #777; font-weight: normal; }
+";
+
+/// Pages written by [`publish`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublishSummary {
+    pub days: usize,
+    pub pages: usize,
+}
+
+/// Render a vault as a static, offline HTML site
+///
+/// Layout: `index.html` (years), `YYYY/index.html` (months), `YYYY/MM/index.html`
+/// (days), `YYYY/MM/DD.html` (one page per daily file) and `category/NAME.html`
+/// for each category with a configured section. Everything links relatively and
+/// a single local stylesheet is used, so the output can be browsed from disk.
+pub fn publish(vault: &Vault, out: &Path) -> Result<PublishSummary, JourneyError> {
+    let files = vault.daily_note_files()?;
+    let mut pages = 0;
+
+    fs::create_dir_all(out)?;
+    fs::write(out.join("style.css"), STYLESHEET)?;
+
+    // Day pages with prev/next navigation
+    for (i, (date, path)) in files.iter().enumerate() {
+        let content = fs::read_to_string(path)?;
+        let prev = i.checked_sub(1).map(|p| files[p].0);
+        let next = files.get(i + 1).map(|(d, _)| *d);
+        write_page(out, &day_path(*date), &render_day(vault, *date, &content, prev, next))?;
+        pages += 1;
+    }
+
+    // Year, month and top-level indexes
+    let mut years: BTreeMap<i32, BTreeMap<u32, Vec<NaiveDate>>> = BTreeMap::new();
+    for (date, _) in &files {
+        years.entry(date.year()).or_default().entry(date.month()).or_default().push(*date);
+    }
+
+    for (year, months) in &years {
+        for (month, days) in months {
+            let items: Vec<String> = days.iter()
+                .map(|d| format!("<li><a href=\"{:02}.html\">{}</a></li>", d.day(), escape(&vault.date_handler.format_date(*d))))
+                .collect();
+            let body = format!("<nav><a href=\"../index.html\">{}</a></nav>\n<h1>{}-{:02}</h1>\n<ul>\n{}\n</ul>", year, year, month, items.join("\n"));
+            write_page(out, &format!("{}/{:02}/index.html", year, month), &page(&format!("{}-{:02}", year, month), 2, &body))?;
+            pages += 1;
+        }
+
+        let items: Vec<String> = months.iter()
+            .map(|(month, days)| format!("<li><a href=\"{:02}/index.html\">{}-{:02}</a> ({} days)</li>", month, year, month, days.len()))
+            .collect();
+        let body = format!("<nav><a href=\"../index.html\">All years</a></nav>\n<h1>{}</h1>\n<ul>\n{}\n</ul>", year, items.join("\n"));
+        write_page(out, &format!("{}/index.html", year), &page(&year.to_string(), 1, &body))?;
+        pages += 1;
+    }
+
+    // Categories get their own page when the vault configures a section for them
+    let mut stems: HashMap<String, usize> = HashMap::new();
+    let categories: Vec<(&str, &String, String)> = vault.config.categories().into_iter()
+        .map(|(category, section)| {
+            // Category names are user-defined, so pages are named after a slug, numbered on collisions
+            let slug = slug(category);
+            let count = stems.entry(slug.clone()).or_insert(0);
+            *count += 1;
+            let stem = if *count == 1 { slug } else { format!("{}-{}", slug, count) };
+            (category, &section.header, stem)
+        })
+        .collect();
+
+    for (category, header, stem) in &categories {
+        write_page(out, &format!("category/{}.html", stem), &render_category(vault, category, header, &files)?)?;
+        pages += 1;
+    }
+
+    let year_items: Vec<String> = years.iter()
+        .map(|(year, months)| format!("<li><a href=\"{}/index.html\">{}</a> ({} days)</li>", year, year, months.values().map(Vec::len).sum::<usize>()))
+        .collect();
+    let mut body = format!("<h1>{}</h1>\n<h2>Years</h2>\n<ul>\n{}\n</ul>", escape(&vault.config.name), year_items.join("\n"));
+    if !categories.is_empty() {
+        let items: Vec<String> = categories.iter()
+            .map(|(_, header, stem)| format!("<li><a href=\"category/{}.html\">{}</a></li>", escape(stem), escape(header)))
+            .collect();
+        body.push_str(&format!("\n<h2>Categories</h2>\n<ul>\n{}\n</ul>", items.join("\n")));
+    }
+    write_page(out, "index.html", &page(&vault.config.name, 0, &body))?;
+    pages += 1;
+
+    Ok(PublishSummary { days: files.len(), pages })
+}
+
+/// Relative path of a day page from the site root
+fn day_path(date: NaiveDate) -> String {
+    format!("{}/{:02}/{:02}.html", date.year(), date.month(), date.day())
+}
+
+fn render_day(vault: &Vault, date: NaiveDate, content: &str, prev: Option<NaiveDate>, next: Option<NaiveDate>) -> String {
+    let notes = vault.parse_notes(content, date);
+    let by_line: HashMap<usize, &Note> = notes.iter().map(|note| (note.line_span.start, note)).collect();
+    let codec = vault.codec();
+    let mut anchors = Anchors::default();
+
+    let lines: Vec<&str> = content.lines().collect();
+    let mut html = Vec::new();
+    let mut in_list = false;
+    let mut i = skip_frontmatter(&lines);
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+
+        if let Some(note) = by_line.get(&i) {
+            if !in_list {
+                html.push("<ul class=\"notes\">".to_string());
+                in_list = true;
+            }
+            html.push(render_note(note, &mut anchors));
+            i = note.line_span.end;
+            continue;
+        }
+
+        if in_list && !line.is_empty() {
+            html.push("</ul>".to_string());
+            in_list = false;
+        }
+
+        if line.starts_with('#') {
+            let level = line.chars().take_while(|c| *c == '#').count().min(5) + 1;
+            html.push(format!("<h{}>{}</h{}>", level, escape(line.trim_start_matches('#').trim()), level));
+        } else if !line.is_empty() && !codec.is_header_line(line) {
+            html.push(format!("<p>{}</p>", escape(line)));
+        }
+        i += 1;
+    }
+    if in_list {
+        html.push("</ul>".to_string());
+    }
+
+    let link = |date: Option<NaiveDate>, label: &str| match date {
+        Some(d) => format!("<a href=\"../../{}\">{}</a>", day_path(d), label),
+        None => "<span></span>".to_string(),
+    };
+    let nav = format!("<nav>{}<a href=\"index.html\">{}-{:02}</a>{}</nav>",
+        link(prev, "&larr; Previous"),
+        date.year(),
+        date.month(),
+        link(next, "Next &rarr;"));
+
+    let title = vault.date_handler.format_date(date);
+    let body = format!("{}\n<h1>{} <small>{}</small></h1>\n{}\n{}", nav, escape(&title), date.format("%A"), html.join("\n"), nav);
+    page(&title, 2, &body)
+}
+
+fn render_category(vault: &Vault, category: &str, header: &str, files: &[(NaiveDate, PathBuf)]) -> Result<String, JourneyError> {
+    let mut sections = Vec::new();
+
+    for (date, _) in files {
+        let notes = vault.read_notes(*date, Some(category))?;
+        if notes.is_empty() {
+            continue;
+        }
+
+        // Anchors are recomputed per day so links match the day page's ids
+        let mut anchors = Anchors::default();
+        let all_anchors: HashMap<usize, String> = vault.read_all_notes(*date)?
+            .iter()
+            .map(|note| (note.line_span.start, anchors.next(note)))
+            .collect();
+
+        let items: Vec<String> = notes.iter().map(|note| {
+            let href = match all_anchors.get(&note.line_span.start) {
+                Some(anchor) => format!("../{}#{}", day_path(*date), anchor),
+                None => format!("../{}", day_path(*date)),
+            };
+            format!("<li><a class=\"time\" href=\"{}\">{}</a>{}</li>", href, escape(note.timestamp.as_deref().unwrap_or("--:--")), render_content(&note.content))
+        }).collect();
+
+        sections.push(format!("<h2><a href=\"../{}\">{}</a></h2>\n<ul class=\"notes\">\n{}\n</ul>",
+            day_path(*date),
+            escape(&vault.date_handler.format_date(*date)),
+            items.join("\n")));
+    }
+
+    let body = format!("<nav><a href=\"../index.html\">{}</a></nav>\n<h1>{}</h1>\n{}", escape(&vault.config.name), escape(header), sections.join("\n"));
+    Ok(page(header, 1, &body))
+}
+
+fn render_note(note: &Note, anchors: &mut Anchors) -> String {
+    let class = if note.task == Some(crate::note::TaskStatus::Done) { " class=\"done\"" } else { "" };
+    let checkbox = match note.task {
+        Some(crate::note::TaskStatus::Open) => "&#9744; ",
+        Some(crate::note::TaskStatus::Done) => "&#9745; ",
+        None => "",
+    };
+
+    match note.timestamp.as_deref() {
+        Some(timestamp) => {
+            let anchor = anchors.next(note);
+            format!("<li id=\"{}\"{}><a class=\"time\" href=\"#{}\">{}</a>{}{}</li>", anchor, class, anchor, escape(timestamp), checkbox, render_content(&note.content))
+        }
+        None => format!("<li{}>{}{}</li>", class, checkbox, render_content(&note.content)),
+    }
+}
+
+fn render_content(content: &str) -> String {
+    content.lines().map(escape).collect::<Vec<_>>().join("<br>")
+}
+
+/// Unique per-page anchor ids derived from note times ("t-1430", "t-1430-2", ...)
+#[derive(Default)]
+struct Anchors {
+    used: HashMap<String, usize>,
+}
+
+impl Anchors {
+    fn next(&mut self, note: &Note) -> String {
+        let base = match note.time() {
+            Some(time) => format!("t-{}", time.format("%H%M%S")),
+            None => "note".to_string(),
+        };
+        let count = self.used.entry(base.clone()).or_insert(0);
+        *count += 1;
+        if *count == 1 { base } else { format!("{}-{}", base, count) }
+    }
+}
+
+/// Index of the first line after YAML frontmatter
+fn skip_frontmatter(lines: &[&str]) -> usize {
+    if lines.first().map(|l| l.trim()) != Some("---") {
+        return 0;
+    }
+    lines.iter().skip(1).position(|l| l.trim() == "---").map_or(0, |end| end + 2)
+}
+
+/// Wrap a page body in an HTML document; `depth` is the number of directories below the site root
+fn page(title: &str, depth: usize, body: &str) -> String {
+    format!("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<title>{}</title>\n<link rel=\"stylesheet\" href=\"{}style.css\">\n</head>\n<body>\n{}\n</body>\n</html>\n",
+        escape(title),
+        "../".repeat(depth),
+        body)
+}
+
+fn write_page(out: &Path, relative: &str, html: &str) -> Result<(), JourneyError> {
+    let path = out.join(relative);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, html)?;
+    Ok(())
+}
+
+/// A file stem made of lowercase ASCII letters, digits and single dashes
+fn slug(text: &str) -> String {
+    let slug = text.to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() { "category".to_string() } else { slug }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
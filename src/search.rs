@@ -0,0 +1,49 @@
+use crate::date_time::DateRange;
+use crate::errors::JourneyError;
+use crate::note::Note;
+use crate::vault::Vault;
+use regex::{Regex, RegexBuilder};
+
+/// A compiled search pattern matched against note content
+pub enum SearchQuery {
+    /// Plain substring match
+    Substring { needle: String, case_sensitive: bool },
+    /// Regular expression match
+    Pattern(Regex),
+}
+
+impl SearchQuery {
+    /// Case-insensitive unless `case_sensitive` is set; `regex` treats the query as a regular expression
+    pub fn new(query: &str, regex: bool, case_sensitive: bool) -> Result<Self, JourneyError> {
+        if regex {
+            let pattern = RegexBuilder::new(query)
+                .case_insensitive(!case_sensitive)
+                .build()
+                .map_err(|e| JourneyError::InvalidPattern(e.to_string()))?;
+            Ok(SearchQuery::Pattern(pattern))
+        } else if case_sensitive {
+            Ok(SearchQuery::Substring { needle: query.to_string(), case_sensitive })
+        } else {
+            Ok(SearchQuery::Substring { needle: query.to_lowercase(), case_sensitive })
+        }
+    }
+
+    pub fn matches(&self, text: &str) -> bool {
+        match self {
+            SearchQuery::Substring { needle, case_sensitive: true } => text.contains(needle.as_str()),
+            SearchQuery::Substring { needle, case_sensitive: false } => text.to_lowercase().contains(needle.as_str()),
+            SearchQuery::Pattern(pattern) => pattern.is_match(text),
+        }
+    }
+}
+
+/// Search the notes of a vault, optionally limited to a date range and a category's section
+///
+/// Without a category every note in each daily file is searched; headers,
+/// frontmatter and table separators are never matched.
+pub fn search_vault(vault: &Vault, query: &SearchQuery, range: Option<DateRange>, category: Option<&str>) -> Result<Vec<Note>, JourneyError> {
+    Ok(vault.collect_notes(range, category)?
+        .into_iter()
+        .filter(|note| query.matches(&note.content))
+        .collect())
+}
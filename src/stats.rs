@@ -0,0 +1,118 @@
+use crate::config::VaultConfig;
+use crate::note::Note;
+use chrono::{Duration, NaiveDate, Timelike};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Note and word totals for one category or section
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SectionStats {
+    /// Section heading, or "(none)" for notes outside any section
+    pub section: String,
+    /// Category whose configured section header matches, if any
+    pub category: Option<String>,
+    pub notes: usize,
+    pub words: usize,
+}
+
+/// How consistently (and how much) a vault has been written to
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    pub first_day: Option<NaiveDate>,
+    pub last_day: Option<NaiveDate>,
+    pub days_with_entries: usize,
+    pub notes: usize,
+    /// Consecutive days with entries up to today (or yesterday, if today has none yet)
+    pub current_streak: usize,
+    pub longest_streak: usize,
+    /// Mean over the days that have entries
+    pub notes_per_day_mean: f64,
+    pub notes_per_day_max: usize,
+    /// Hour of day (0-23) with the most timed notes; the earliest wins ties
+    pub busiest_hour: Option<u32>,
+    pub busiest_hour_notes: usize,
+    pub words: usize,
+    pub words_per_note_mean: f64,
+    pub sections: Vec<SectionStats>,
+}
+
+impl Stats {
+    /// Compute statistics from notes, counting streaks relative to `today`
+    pub fn compute(notes: &[Note], config: &VaultConfig, today: NaiveDate) -> Self {
+        let mut per_day: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+        let mut per_hour: BTreeMap<u32, usize> = BTreeMap::new();
+        let mut sections: Vec<SectionStats> = Vec::new();
+        let mut words = 0;
+
+        for note in notes {
+            *per_day.entry(note.date).or_default() += 1;
+            if let Some(time) = note.time() {
+                *per_hour.entry(time.hour()).or_default() += 1;
+            }
+
+            let note_words = note.content.split_whitespace().count();
+            words += note_words;
+
+            let section = note.section.clone().unwrap_or_else(|| "(none)".to_string());
+            let category = note.section.as_deref().and_then(|s| config.category_for_section(s)).map(str::to_string);
+            match sections.iter_mut().find(|s| s.section == section) {
+                Some(entry) => {
+                    entry.notes += 1;
+                    entry.words += note_words;
+                }
+                None => sections.push(SectionStats { section, category, notes: 1, words: note_words }),
+            }
+        }
+        sections.sort_by_key(|s| std::cmp::Reverse(s.notes));
+
+        let days: BTreeSet<NaiveDate> = per_day.keys().copied().collect();
+        let (busiest_hour, busiest_hour_notes) = per_hour.iter()
+            .fold((None, 0), |best, (hour, count)| if *count > best.1 { (Some(*hour), *count) } else { best });
+
+        Self {
+            first_day: days.first().copied(),
+            last_day: days.last().copied(),
+            days_with_entries: days.len(),
+            notes: notes.len(),
+            current_streak: current_streak(&days, today),
+            longest_streak: longest_streak(&days),
+            notes_per_day_mean: mean(notes.len(), days.len()),
+            notes_per_day_max: per_day.values().copied().max().unwrap_or(0),
+            busiest_hour,
+            busiest_hour_notes,
+            words,
+            words_per_note_mean: mean(words, notes.len()),
+            sections,
+        }
+    }
+}
+
+fn mean(total: usize, count: usize) -> f64 {
+    if count == 0 { 0.0 } else { total as f64 / count as f64 }
+}
+
+fn current_streak(days: &BTreeSet<NaiveDate>, today: NaiveDate) -> usize {
+    // A streak is not broken until the day is over
+    let mut day = if days.contains(&today) { today } else { today - Duration::days(1) };
+    let mut streak = 0;
+    while days.contains(&day) {
+        streak += 1;
+        day -= Duration::days(1);
+    }
+    streak
+}
+
+fn longest_streak(days: &BTreeSet<NaiveDate>) -> usize {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous: Option<NaiveDate> = None;
+    for day in days {
+        run = match previous {
+            Some(prev) if *day - prev == Duration::days(1) => run + 1,
+            _ => 1,
+        };
+        longest = longest.max(run);
+        previous = Some(*day);
+    }
+    longest
+}
@@ -0,0 +1,36 @@
+use crate::note::Note;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+/// Usage summary for one tag across a set of notes
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagSummary {
+    /// Tag as first written (tags are grouped case-insensitively)
+    pub tag: String,
+    pub count: usize,
+    pub first_seen: NaiveDate,
+    pub last_seen: NaiveDate,
+}
+
+/// Build a tag index from notes, sorted by count (descending) then tag name
+pub fn tag_index(notes: &[Note]) -> Vec<TagSummary> {
+    let mut index: HashMap<String, TagSummary> = HashMap::new();
+
+    for note in notes {
+        for tag in note.tags() {
+            let summary = index.entry(tag.to_lowercase()).or_insert_with(|| TagSummary {
+                tag: tag.clone(),
+                count: 0,
+                first_seen: note.date,
+                last_seen: note.date,
+            });
+            summary.count += 1;
+            summary.first_seen = summary.first_seen.min(note.date);
+            summary.last_seen = summary.last_seen.max(note.date);
+        }
+    }
+
+    let mut summaries: Vec<TagSummary> = index.into_values().collect();
+    summaries.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.to_lowercase().cmp(&b.tag.to_lowercase())));
+    summaries
+}
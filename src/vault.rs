@@ -1,7 +1,8 @@
 use crate::config::{VaultConfig, NoteFormat};
-use crate::date_time::DateTimeHandler;
+use crate::date_time::{Clock, DateRange, DateTimeHandler, Period};
 use crate::errors::JourneyError;
-use chrono::{DateTime, Local, NaiveDate, Datelike, Weekday};
+use crate::note::{is_continuation, Note, NoteCodec, NoteSelector, TaskStatus};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Datelike, TimeZone, Weekday};
 use std::fs;
 use std::path::PathBuf;
 
@@ -10,21 +11,49 @@ pub struct Vault {
     pub date_handler: DateTimeHandler,
 }
 
+/// A note waiting to be written by [`Vault::add_notes`]
+#[derive(Debug, Clone)]
+pub struct PendingNote {
+    pub content: String,
+    pub timestamp: DateTime<FixedOffset>,
+    pub category: Option<String>,
+}
+
+/// Result of writing a batch of notes to one daily file
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchOutcome {
+    pub path: PathBuf,
+    /// The daily file did not exist before the batch
+    pub created: bool,
+    pub added: usize,
+    /// Notes already present in the file
+    pub skipped: usize,
+}
+
 impl Vault {
     pub fn new(mut config: VaultConfig) -> Self {
+        config.migrate_section_headers();
         // Backward-compat: map legacy note_format to list_type when not set
         if config.list_type.is_none() {
             if let Some(fmt) = config.note_format.clone() {
                 config.list_type = Some(fmt);
             }
         }
-        let date_handler = DateTimeHandler::new(config.locale.clone());
+        let date_handler = DateTimeHandler::new(config.locale.clone())
+            .with_zone(config.timezone.unwrap_or_default())
+            .with_day_start(config.day_starts_at.unwrap_or(NaiveTime::MIN));
         Self {
             config,
             date_handler,
         }
     }
 
+    /// Read "now" from `clock` instead of the system clock
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.date_handler.clock = clock;
+        self
+    }
+
     /// Get locale-dependent table headers
     pub fn get_table_headers(&self) -> (String, String) {
         // Check for custom table headers first
@@ -92,10 +121,20 @@ impl Vault {
         }
     }
 
+    /// Codec for the vault's configured note format
+    pub fn codec(&self) -> NoteCodec {
+        self.codec_for(self.config.list_type.as_ref().unwrap_or(&NoteFormat::Bullet))
+    }
+
+    /// Codec for an arbitrary note format, using this vault's table headers
+    pub fn codec_for(&self, format: &NoteFormat) -> NoteCodec {
+        NoteCodec::new(format.clone(), self.get_table_headers())
+    }
+
     pub fn get_note_path(&self, date: NaiveDate) -> PathBuf {
         if let Some(ref format) = self.config.file_path_format {
             // Use custom file path format
-            let formatted_path = self.format_custom_path(format, date);
+            let formatted_path = self.format_custom_path(format, date, date);
             self.config.path.join(formatted_path)
         } else {
             // Use default format: YYYY-MM-DD.md
@@ -104,141 +143,257 @@ impl Vault {
         }
     }
 
+    /// Path of the weekly, monthly, quarterly or yearly note containing the date
+    ///
+    /// Uses the vault's `weekly_format` (etc.) or the period's default, with the
+    /// same placeholders as `file_path_format` evaluated at the first day of the period
+    /// (week numbers follow `Period::label`).
+    pub fn get_period_path(&self, period: Period, date: NaiveDate) -> PathBuf {
+        let start = period.range(date, self.config.week_starts_on()).start;
+        let format = self.config.period_format(period).map_or(period.default_path_format(), String::as_str);
+        self.config.path.join(self.format_custom_path(format, start, crate::date_time::week_number_day(start)))
+    }
+
     /// Backwards-compatible API: add a note without category
-    pub fn add_note(&self, content: &str, timestamp: Option<DateTime<Local>>) -> Result<(), JourneyError> {
+    pub fn add_note<Tz: TimeZone>(&self, content: &str, timestamp: Option<DateTime<Tz>>) -> Result<(), JourneyError> {
         self.add_note_with_category(content, timestamp, None)
     }
 
     /// Category-aware API used by the application
-    pub fn add_note_with_category(&self, content: &str, timestamp: Option<DateTime<Local>>, category: Option<&str>) -> Result<(), JourneyError> {
-        let timestamp = timestamp.unwrap_or_else(|| self.date_handler.get_current_datetime());
-        let date = timestamp.date_naive();
-        let note_path = self.get_note_path(date);
+    pub fn add_note_with_category<Tz: TimeZone>(&self, content: &str, timestamp: Option<DateTime<Tz>>, category: Option<&str>) -> Result<(), JourneyError> {
+        // Expand phrases in the content
+        let expanded_content = self.expand_phrases(content);
+        self.insert_note(&expanded_content, None, timestamp, category)
+    }
+
+    /// Add an open task ("- [ ] [time] content")
+    pub fn add_task_with_category<Tz: TimeZone>(&self, content: &str, timestamp: Option<DateTime<Tz>>, category: Option<&str>) -> Result<(), JourneyError> {
+        let expanded_content = self.expand_phrases(content);
+        self.insert_note(&expanded_content, Some(TaskStatus::Open), timestamp, category)
+    }
+
+    /// Insert already-expanded note content in chronological order, creating the file or section as needed
+    ///
+    /// The timestamp keeps its own UTC offset; without one the vault's current time is used.
+    pub fn insert_note<Tz: TimeZone>(&self, expanded_content: &str, task: Option<TaskStatus>, timestamp: Option<DateTime<Tz>>, category: Option<&str>) -> Result<(), JourneyError> {
+        let timestamp = timestamp.map_or_else(|| self.date_handler.get_current_datetime(), |t| t.fixed_offset());
+        let note_path = self.get_note_path(self.date_handler.logical_date(timestamp.naive_local()));
 
         // Ensure the vault directory exists
         if let Some(parent) = note_path.parent() {
             fs::create_dir_all(parent)?;
         }
 
-        // Expand phrases in the content
+        let existing_content = if note_path.exists() { Some(fs::read_to_string(&note_path)?) } else { None };
+        let content = self.place_note(existing_content.as_deref(), expanded_content, task, timestamp, category, None)?;
+        fs::write(&note_path, content)?;
+
+        Ok(())
+    }
+
+    /// Add a note (or task) to the periodic note containing the timestamp
+    ///
+    /// Notes in periodic files carry the full date ("[2025-10-24 14:30:00]") and
+    /// are kept in chronological order; missing files are created from the period's template.
+    pub fn add_period_note<Tz: TimeZone>(&self, period: Period, content: &str, task: Option<TaskStatus>, timestamp: DateTime<Tz>, category: Option<&str>) -> Result<(), JourneyError> {
+        let timestamp = timestamp.fixed_offset();
         let expanded_content = self.expand_phrases(content);
-        let formatted_time = self.date_handler.format_datetime(timestamp);
-        
-        // Get the configured note format (default to bullet if not specified)
-        let note_format = self.config.list_type.as_ref().unwrap_or(&NoteFormat::Bullet);
-        let note_entry = self.format_note_entry(&formatted_time, &expanded_content, note_format);
+        let note_path = self.get_period_path(period, self.date_handler.logical_date(timestamp.naive_local()));
 
-        // Check if file exists and has content
-        if note_path.exists() {
-            let existing_content = fs::read_to_string(&note_path)?;
-            
-            // Check if we need to convert the existing format
-            let converted_content = self.convert_note_format_if_needed(&existing_content, note_format)?;
-            
-            // If section_header is specified, find and insert into that section in chronological order
-            if let Some(section_name) = self.config.get_section_header(category) {
-                if let Some(section_start) = self.find_section(&converted_content, section_name) {
-                    let mut lines: Vec<&str> = converted_content.lines().collect();
-                    
-                    // Find insertion index to keep chronological order within the section
-                    let insertion_index = self.find_insertion_index(
-                        &lines,
-                        section_start,
-                        &formatted_time,
-                        self.config.list_type.as_ref().unwrap_or(&NoteFormat::Bullet)
-                    );
-                    
-                    // Insert the note at the chosen index
-                    lines.insert(insertion_index, &note_entry);
-                    
-                    fs::write(&note_path, lines.join("\n"))?;
-                    return Ok(());
+        if let Some(parent) = note_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let existing_content = if note_path.exists() { Some(fs::read_to_string(&note_path)?) } else { None };
+        let content = self.place_note(existing_content.as_deref(), &expanded_content, task, timestamp, category, Some(period))?;
+        fs::write(&note_path, content)?;
+
+        Ok(())
+    }
+
+    /// Replace the top-level `# {heading}` section of a periodic note, appending it if absent
+    ///
+    /// `body` must start with its own `# {heading}...` line. A missing file is
+    /// created from the period's template (or the default frontmatter) first.
+    pub fn write_period_section(&self, period: Period, date: NaiveDate, heading: &str, body: &str) -> Result<PathBuf, JourneyError> {
+        let note_path = self.get_period_path(period, date);
+        if let Some(parent) = note_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let existing = if note_path.exists() {
+            fs::read_to_string(&note_path)?
+        } else {
+            let start = period.range(date, self.config.week_starts_on()).start;
+            match self.config.period_template(period) {
+                Some(template_file) => self.create_file_from_template(template_file, self.date_handler.combine_date_time(start, NaiveTime::MIN), "", Some(period))?,
+                None => self.create_default_file_content(start, "", None, Some(period))?,
+            }
+        };
+
+        let lines: Vec<&str> = existing.lines().collect();
+        let prefix = format!("# {}", heading);
+        let content = match lines.iter().position(|line| line.starts_with(&prefix)) {
+            Some(start) => {
+                let end = lines[start + 1..].iter().position(|line| line.starts_with("# ")).map_or(lines.len(), |i| start + 1 + i);
+                let mut replaced: Vec<&str> = lines[..start].to_vec();
+                replaced.extend(body.trim_end().lines());
+                if end < lines.len() {
+                    replaced.push("");
+                    replaced.extend(&lines[end..]);
+                }
+                replaced.join("\n") + "\n"
+            }
+            None => {
+                let existing = existing.trim_end();
+                if existing.is_empty() {
+                    format!("{}\n", body.trim_end())
                 } else {
-                    // Section doesn't exist, create it at the end
-                    let mut new_content = converted_content;
-                    if !new_content.ends_with('\n') {
-                        new_content.push('\n');
-                    }
-                    new_content.push_str(&format!("\n# {}\n", section_name));
-                    new_content.push_str(&note_entry);
-                    fs::write(&note_path, new_content)?;
-                    return Ok(());
+                    format!("{}\n\n{}\n", existing, body.trim_end())
                 }
             }
-            
-            // No section configured: insert in chronological order across the whole file
-            let mut lines: Vec<&str> = converted_content.lines().collect();
-            let insertion_index = self.find_insertion_index(
-                &lines,
-                0,
-                &formatted_time,
-                self.config.list_type.as_ref().unwrap_or(&NoteFormat::Bullet)
-            );
-            lines.insert(insertion_index, &note_entry);
-            fs::write(&note_path, lines.join("\n"))?;
-        } else {
+        };
+
+        fs::write(&note_path, content)?;
+        Ok(note_path)
+    }
+
+    /// Add several notes to one day's file with a single write
+    ///
+    /// Phrases are expanded as for `add_note_with_category`. Notes whose time
+    /// and content already appear in the file (or earlier in the batch) are skipped.
+    pub fn add_notes(&self, date: NaiveDate, notes: &[PendingNote]) -> Result<BatchOutcome, JourneyError> {
+        let note_path = self.get_note_path(date);
+        if let Some(parent) = note_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let existed = note_path.exists();
+        let mut content = if existed { Some(fs::read_to_string(&note_path)?) } else { None };
+        let mut outcome = BatchOutcome { path: note_path.clone(), created: !existed, added: 0, skipped: 0 };
+
+        for pending in notes {
+            let expanded = self.expand_phrases(&pending.content);
+            let time = crate::note::parse_timestamp(&self.date_handler.format_datetime(pending.timestamp));
+            let is_duplicate = content.as_deref().is_some_and(|existing| {
+                self.parse_notes(existing, date).iter().any(|note| note.content == expanded && note.time() == time)
+            });
+
+            if is_duplicate {
+                outcome.skipped += 1;
+            } else {
+                content = Some(self.place_note(content.as_deref(), &expanded, None, pending.timestamp, pending.category.as_deref(), None)?);
+                outcome.added += 1;
+            }
+        }
+
+        if outcome.added > 0 {
+            if let Some(content) = content {
+                fs::write(&note_path, content)?;
+            }
+        }
+        Ok(outcome)
+    }
+
+    /// The day's file content with a note placed in chronological order
+    ///
+    /// `existing` is the current file content, or None when the file does not exist yet.
+    /// `period` is set when the file is a periodic note rather than a daily one.
+    fn place_note(&self, existing: Option<&str>, expanded_content: &str, task: Option<TaskStatus>, timestamp: DateTime<FixedOffset>, category: Option<&str>, period: Option<Period>) -> Result<String, JourneyError> {
+        // Reject unknown categories up front, whether or not the file exists yet
+        let section_header = self.config.get_section_header(category)?;
+        let date = self.date_handler.logical_date(timestamp.naive_local());
+        let mut formatted_time = match period {
+            Some(_) => timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+            None => self.date_handler.format_note_time(timestamp.naive_local()),
+        };
+        if self.config.record_utc_offset {
+            formatted_time.push_str(&timestamp.format(" %:z").to_string());
+        }
+        
+        // Get the configured note format (default to bullet if not specified)
+        let codec = self.codec();
+        let note_line = codec.render(&Note { task, ..Note::new(date, Some(formatted_time), expanded_content) });
+
+        let Some(existing_content) = existing else {
+            let note_entry = format!("{}\n", note_line);
+
             // Create new file
-            let file_content = if let Some(template_file) = &self.config.template_file {
+            let template_file = match period {
+                Some(period) => self.config.period_template(period),
+                None => self.config.template_file.as_ref(),
+            };
+            return if let Some(template_file) = template_file {
                 // Use template file
-                self.create_file_from_template(template_file, timestamp, &note_entry)?
+                self.create_file_from_template(template_file, timestamp, &note_entry, period)
             } else {
                 // Use default template
-                self.create_default_file_content(date, &note_entry, category)
+                self.create_default_file_content(date, &note_entry, category, period)
             };
-            
-            fs::write(&note_path, file_content)?;
-        }
+        };
 
-        Ok(())
+        // Check if we need to convert the existing format
+        let converted_content = self.convert_note_format_if_needed(existing_content, codec.format(), date)?;
+        
+        // If section_header is specified, find and insert into that section in chronological order
+        if let Some(section_name) = section_header {
+            if let Some(section_start) = self.find_section(&converted_content, section_name) {
+                let mut lines: Vec<&str> = converted_content.lines().collect();
+                
+                // Find insertion index to keep chronological order within the section
+                let insertion_index = self.find_insertion_index(&lines, section_start, timestamp.naive_local(), &codec, date);
+                
+                // Insert the note at the chosen index
+                lines.insert(insertion_index, &note_line);
+                
+                return Ok(lines.join("\n") + "\n");
+            } else {
+                // Section doesn't exist, create it at the end
+                let mut new_content = converted_content;
+                if !new_content.ends_with('\n') {
+                    new_content.push('\n');
+                }
+                new_content.push_str(&format!("\n{} {}\n", "#".repeat(self.config.section_level(category)), section_name));
+                new_content.push_str(&note_line);
+                new_content.push('\n');
+                return Ok(new_content);
+            }
+        }
+        
+        // No section configured: insert in chronological order across the whole file
+        let mut lines: Vec<&str> = converted_content.lines().collect();
+        let insertion_index = self.find_insertion_index(&lines, 0, timestamp.naive_local(), &codec, date);
+        lines.insert(insertion_index, &note_line);
+        Ok(lines.join("\n") + "\n")
     }
 
-    fn create_default_file_content(&self, date: NaiveDate, note_entry: &str, category: Option<&str>) -> String {
+    fn create_default_file_content(&self, date: NaiveDate, note_entry: &str, category: Option<&str>, period: Option<Period>) -> Result<String, JourneyError> {
         let mut file_content = String::new();
         
         // Add frontmatter
         file_content.push_str("---\n");
-        file_content.push_str(&format!("date: {}\n", self.date_handler.format_date(date)));
+        match period {
+            Some(period) => file_content.push_str(&format!("period: {}\n", period.label(date, self.config.week_starts_on()))),
+            None => file_content.push_str(&format!("date: {}\n", self.date_handler.format_date(date))),
+        }
         file_content.push_str("---\n\n");
         
         // Add section if specified
-        if let Some(section_name) = self.config.get_section_header(category) {
-            file_content.push_str(&format!("# {}\n\n", section_name));
+        if let Some(section_name) = self.config.get_section_header(category)? {
+            file_content.push_str(&format!("{} {}\n\n", "#".repeat(self.config.section_level(category)), section_name));
         }
         
         // Add table header if using table format
-        let note_format = self.config.list_type.as_ref().unwrap_or(&NoteFormat::Bullet);
-        if note_format == &NoteFormat::Table {
-            let (time_header, content_header) = self.get_table_headers();
-            file_content.push_str(&format!("| {} | {} |\n", time_header, content_header));
-            file_content.push_str("|------|----------|\n");
+        for header_line in self.codec().header_lines() {
+            file_content.push_str(&header_line);
+            file_content.push('\n');
         }
         
         file_content.push_str(note_entry);
-        file_content
+        Ok(file_content)
     }
 
-    /// Determine if a line is a table header or separator line
-    fn is_table_header_line(&self, line: &str) -> bool {
-        let trimmed = line.trim();
-
-        // Separator like |---|
-        if trimmed.starts_with("|---") {
-            return true;
-        }
-
-        // Match against localized/custom headers
-        if trimmed.starts_with('|') {
-            let (time_header, content_header) = self.get_table_headers();
-            let expected = format!("| {} | {} |", time_header, content_header);
-            if trimmed == expected {
-                return true;
-            }
-        }
-
-        false
-    }
-
-    fn create_file_from_template(&self, template_file: &str, timestamp: DateTime<Local>, note_entry: &str) -> Result<String, JourneyError> {
+    fn create_file_from_template(&self, template_file: &str, timestamp: DateTime<FixedOffset>, note_entry: &str, period: Option<Period>) -> Result<String, JourneyError> {
         // Read the template file
         let template_path = PathBuf::from(template_file);
         let template_content = fs::read_to_string(&template_path)
@@ -248,7 +403,21 @@ impl Vault {
         let mut processed_content = template_content;
         
         // Replace template variables
-        let date = timestamp.date_naive();
+        let date = self.date_handler.logical_date(timestamp.naive_local());
+
+        // Periodic notes: the period's name and its first and last day
+        if let Some(period) = period {
+            let range = period.range(date, self.config.week_starts_on());
+            let label = period.label(date, self.config.week_starts_on());
+            let start = self.date_handler.format_date(range.start);
+            let end = self.date_handler.format_date(range.end);
+            processed_content = processed_content.replace("{{period}}", &label);
+            processed_content = processed_content.replace("{period}", &label);
+            processed_content = processed_content.replace("{{period_start}}", &start);
+            processed_content = processed_content.replace("{period_start}", &start);
+            processed_content = processed_content.replace("{{period_end}}", &end);
+            processed_content = processed_content.replace("{period_end}", &end);
+        }
         processed_content = processed_content.replace("{{date}}", &self.date_handler.format_date(date));
         processed_content = processed_content.replace("{{time}}", &self.date_handler.format_time(timestamp.time()));
         processed_content = processed_content.replace("{{datetime}}", &self.date_handler.format_datetime(timestamp));
@@ -316,8 +485,8 @@ impl Vault {
 
     pub fn find_section_end(&self, lines: &[&str], section_start: usize) -> usize {
         // Look for the next section header or end of file
-        for i in (section_start + 1)..lines.len() {
-            if lines[i].trim().starts_with('#') {
+        for (i, line) in lines.iter().enumerate().skip(section_start + 1) {
+            if line.trim().starts_with('#') {
                 return i;
             }
         }
@@ -330,8 +499,8 @@ impl Vault {
         
         // Find the actual end of content in the section (skip blank lines at the end)
         let mut content_end = section_start + 1;
-        for i in section_start + 1..section_end {
-            if !lines[i].trim().is_empty() {
+        for (i, line) in lines.iter().enumerate().take(section_end).skip(section_start + 1) {
+            if !line.trim().is_empty() {
                 content_end = i + 1;
             }
         }
@@ -346,6 +515,7 @@ impl Vault {
 
     /// Category-aware API used by the application
     pub fn list_notes_with_category(&self, date: NaiveDate, category: Option<&str>) -> Result<Vec<String>, JourneyError> {
+        self.config.get_section_header(category)?;
         let note_path = self.get_note_path(date);
         
         if !note_path.exists() {
@@ -354,71 +524,309 @@ impl Vault {
 
         let content = fs::read_to_string(&note_path)?;
         let lines: Vec<&str> = content.lines().collect();
-        let mut notes = Vec::new();
+
+        Ok(self.notes_in_content(&content, date, category)?
+            .into_iter()
+            .map(|note| lines[note.line_span].join("\n"))
+            .collect())
+    }
+
+    /// Raw note lines from the periodic note containing the date
+    pub fn list_period_notes(&self, period: Period, date: NaiveDate, category: Option<&str>) -> Result<Vec<String>, JourneyError> {
+        self.config.get_section_header(category)?;
+        let note_path = self.get_period_path(period, date);
+
+        if !note_path.exists() {
+            return Ok(vec![]);
+        }
+
+        let content = fs::read_to_string(&note_path)?;
+        let lines: Vec<&str> = content.lines().collect();
+
+        Ok(self.notes_in_content(&content, period.range(date, self.config.week_starts_on()).start, category)?
+            .into_iter()
+            .map(|note| lines[note.line_span].join("\n"))
+            .collect())
+    }
+
+    /// Read the notes for a date as typed entries, sorted chronologically
+    pub fn read_notes(&self, date: NaiveDate, category: Option<&str>) -> Result<Vec<Note>, JourneyError> {
+        self.config.get_section_header(category)?;
+        let note_path = self.get_note_path(date);
         
-        // If section_header is configured, only list notes within that section
-        if let Some(section_name) = self.config.get_section_header(category) {
-            if let Some(section_start) = self.find_section(&content, section_name) {
-                let section_end = self.find_section_end(&lines, section_start);
-                
-                // Only process lines within the section
-                for line in &lines[section_start..section_end] {
-                    let trimmed = line.trim();
-                    // Check for bullet format
-                    if trimmed.starts_with("- ") {
-                        notes.push(line.to_string());
-                    }
-                    // Check for table format (but not table headers or separators)
-                    else if trimmed.starts_with("|") && !trimmed.starts_with("|---") && trimmed.contains("|") {
-                        // Skip if it looks like a table header or separator
-                        if !self.is_table_header_line(trimmed) {
-                            notes.push(line.to_string());
-                        }
-                    }
+        if !note_path.exists() {
+            return Ok(vec![]);
+        }
+
+        let content = fs::read_to_string(&note_path)?;
+        let mut notes = self.notes_in_content(&content, date, category)?;
+        for note in &mut notes {
+            note.source = Some(note_path.clone());
+        }
+        Ok(notes)
+    }
+
+    /// Read every note in the daily file for a date, regardless of section
+    pub fn read_all_notes(&self, date: NaiveDate) -> Result<Vec<Note>, JourneyError> {
+        let note_path = self.get_note_path(date);
+        
+        if !note_path.exists() {
+            return Ok(vec![]);
+        }
+
+        let content = fs::read_to_string(&note_path)?;
+        let mut notes = self.parse_notes(&content, date);
+        for note in &mut notes {
+            note.source = Some(note_path.clone());
+        }
+        Ok(notes)
+    }
+
+    /// Collect notes from every daily file, or only the days in a range, sorted by date and time
+    ///
+    /// With a category only that category's section is read; without one every
+    /// note in each file is included.
+    pub fn collect_notes(&self, range: Option<DateRange>, category: Option<&str>) -> Result<Vec<Note>, JourneyError> {
+        // Reject an unknown category even when there are no files to read
+        self.config.get_section_header(category)?;
+        let dates: Vec<NaiveDate> = match range {
+            Some(range) => range.days().filter(|date| self.get_note_path(*date).exists()).collect(),
+            None => self.daily_note_files()?.into_iter().map(|(date, _)| date).collect(),
+        };
+
+        let mut collected = Vec::new();
+        for date in dates {
+            let mut notes = match category {
+                Some(_) => self.read_notes(date, category)?,
+                None => self.read_all_notes(date)?,
+            };
+            notes.sort_by(|a, b| a.cmp_by_time(b));
+            collected.extend(notes);
+        }
+        Ok(collected)
+    }
+
+    /// Find every daily file in the vault, sorted by date
+    ///
+    /// Files are matched against `get_note_path` so custom `file_path_format`
+    /// layouts work; candidate years come from the file paths themselves and,
+    /// failing that, from the `date:` frontmatter field.
+    pub fn daily_note_files(&self) -> Result<Vec<(NaiveDate, PathBuf)>, JourneyError> {
+        let mut markdown_files = Vec::new();
+        collect_markdown_files(&self.config.path, &mut markdown_files)?;
+
+        let mut years = std::collections::BTreeSet::new();
+        for file in &markdown_files {
+            let relative = file.strip_prefix(&self.config.path).unwrap_or(file).to_string_lossy().to_string();
+            let path_years = years_in_text(&relative);
+            if path_years.is_empty() {
+                if let Some(date) = fs::read_to_string(file).ok().and_then(|c| frontmatter_date(&c)) {
+                    years.insert(date.year());
                 }
             }
-            // If section doesn't exist, return empty list
-        } else {
-            // No section configured, list all notes in the file
-            for line in &lines {
-                let trimmed = line.trim();
-                // Check for bullet format
-                if trimmed.starts_with("- ") {
-                    notes.push(line.to_string());
-                }
-                // Check for table format (but not table headers or separators)
-                else if trimmed.starts_with("|") && !trimmed.starts_with("|---") && trimmed.contains("|") {
-                    // Skip if it looks like a table header or separator
-                    if !self.is_table_header_line(trimmed) {
-                        notes.push(line.to_string());
-                    }
-                }
+            years.extend(path_years);
+        }
+
+        // Index the expected path of every day in the candidate years
+        let mut expected = std::collections::HashMap::new();
+        for year in years {
+            let (Some(start), Some(end)) = (NaiveDate::from_ymd_opt(year, 1, 1), NaiveDate::from_ymd_opt(year, 12, 31)) else {
+                continue;
+            };
+            for date in start.iter_days().take_while(|d| *d <= end) {
+                expected.entry(self.get_note_path(date)).or_insert(date);
             }
         }
 
-        // Sort notes by time extracted from the line (ascending chronological)
-        let note_format = self.config.list_type.as_ref().unwrap_or(&NoteFormat::Bullet);
-        notes.sort_by(|a, b| {
-            let ta = self.extract_time_from_note_line(a, note_format);
-            let tb = self.extract_time_from_note_line(b, note_format);
-            
-            // If both have valid times, compare them
-            if let (Some(time_a), Some(time_b)) = (ta, tb) {
-                time_a.cmp(&time_b)
+        let mut files: Vec<(NaiveDate, PathBuf)> = markdown_files
+            .into_iter()
+            .filter_map(|file| expected.get(&file).map(|date| (*date, file)))
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+
+    /// Select a single note from a day's listing (the same order `--list` shows)
+    pub fn select_note(&self, date: NaiveDate, category: Option<&str>, selector: &NoteSelector) -> Result<Note, JourneyError> {
+        let notes = self.read_notes(date, category)?;
+        if notes.is_empty() {
+            return Err(JourneyError::NoteNotFound(format!("no notes for {}", self.date_handler.format_date(date))));
+        }
+        selector.select(&notes).cloned()
+    }
+
+    /// Remove a note from its source file, returning the removed text
+    pub fn remove_note(&self, note: &Note) -> Result<String, JourneyError> {
+        let (path, content) = self.read_note_source(note)?;
+        let mut lines: Vec<&str> = content.lines().collect();
+        let removed = lines.drain(note.line_span.clone()).collect::<Vec<_>>().join("\n");
+
+        self.write_lines(&path, &lines, content.ends_with('\n'))?;
+        Ok(removed)
+    }
+
+    /// Replace a note's content in place (keeping its timestamp and format), returning the new line
+    pub fn amend_note(&self, note: &Note, new_content: &str) -> Result<String, JourneyError> {
+        let (path, content) = self.read_note_source(note)?;
+        let mut lines: Vec<&str> = content.lines().collect();
+
+        let codec = self.codec_for_line(lines[note.line_span.start]);
+        let amended = Note { content: self.expand_phrases(new_content), ..note.clone() };
+        let new_line = codec.render(&amended);
+        lines.splice(note.line_span.clone(), [new_line.as_str()]);
+
+        self.write_lines(&path, &lines, content.ends_with('\n'))?;
+        Ok(new_line)
+    }
+
+    /// Tick off an open task, appending its completion date and time
+    ///
+    /// Returns the rewritten line(s).
+    pub fn complete_task<Tz: TimeZone>(&self, note: &Note, completed_at: DateTime<Tz>) -> Result<String, JourneyError> {
+        let completed_at = completed_at.fixed_offset();
+        match note.task {
+            Some(TaskStatus::Open) => {}
+            Some(TaskStatus::Done) => return Err(JourneyError::NotOpenTask(format!("'{}' is already done", note.content))),
+            None => return Err(JourneyError::NotOpenTask(format!("'{}' has no checkbox", note.content))),
+        }
+
+        let (path, source) = self.read_note_source(note)?;
+        let mut lines: Vec<&str> = source.lines().collect();
+        let codec = self.codec_for_line(lines[note.line_span.start]);
+
+        // The marker goes on the first line, where Obsidian Tasks looks for it
+        let (first, rest) = note.content.split_once('\n').map_or((note.content.as_str(), None), |(f, r)| (f, Some(r)));
+        let mut content = format!("{} ✅ {} {}",
+            first,
+            self.date_handler.format_date(self.date_handler.logical_date(completed_at.naive_local())),
+            completed_at.format("%H:%M"));
+        if let Some(rest) = rest {
+            content.push('\n');
+            content.push_str(rest);
+        }
+        let done = Note { task: Some(TaskStatus::Done), content, ..note.clone() };
+        let new_line = codec.render(&done);
+        lines.splice(note.line_span.clone(), [new_line.as_str()]);
+
+        self.write_lines(&path, &lines, source.ends_with('\n'))?;
+        Ok(new_line)
+    }
+
+    /// Move a note to another date, time, section or vault
+    ///
+    /// The note is removed from its source file and re-inserted through the
+    /// target vault's normal placement, so it lands in chronological order, in
+    /// the target section (created if missing) and in the target's note format.
+    /// If insertion fails the source file is restored.
+    pub fn move_note<Tz: TimeZone>(&self, note: &Note, target: &Vault, timestamp: DateTime<Tz>, category: Option<&str>) -> Result<(), JourneyError> {
+        let (source_path, original) = self.read_note_source(note)?;
+
+        self.remove_note(note)?;
+        if let Err(e) = target.insert_note(&note.content, note.task, Some(timestamp), category) {
+            fs::write(&source_path, original)?;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// The note exactly as written in its source file
+    pub fn note_text(&self, note: &Note) -> Result<String, JourneyError> {
+        let (_, content) = self.read_note_source(note)?;
+        let lines: Vec<&str> = content.lines().collect();
+        Ok(lines[note.line_span.clone()].join("\n"))
+    }
+
+    /// Read a note's source file, checking its line span is still valid
+    fn read_note_source(&self, note: &Note) -> Result<(PathBuf, String), JourneyError> {
+        let path = note.source.clone().unwrap_or_else(|| self.get_note_path(note.date));
+        let content = fs::read_to_string(&path)?;
+        if note.line_span.end > content.lines().count() || note.line_span.is_empty() {
+            return Err(JourneyError::NoteNotFound(format!("{} changed since it was read", path.display())));
+        }
+        Ok((path, content))
+    }
+
+    fn write_lines(&self, path: &std::path::Path, lines: &[&str], trailing_newline: bool) -> Result<(), JourneyError> {
+        let mut content = lines.join("\n");
+        if trailing_newline && !content.is_empty() {
+            content.push('\n');
+        }
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Codec matching the format a line is written in
+    fn codec_for_line(&self, line: &str) -> NoteCodec {
+        if line.trim_start().starts_with('|') {
+            self.codec_for(&NoteFormat::Table)
+        } else {
+            self.codec_for(&NoteFormat::Bullet)
+        }
+    }
+
+    /// Parse every note in a file's content, in either format, tagging each with its section and line span
+    pub fn parse_notes(&self, content: &str, date: NaiveDate) -> Vec<Note> {
+        let bullet = self.codec_for(&NoteFormat::Bullet);
+        let table = self.codec_for(&NoteFormat::Table);
+        let lines: Vec<&str> = content.lines().collect();
+        let mut notes = Vec::new();
+        let mut section: Option<String> = None;
+        let mut in_frontmatter = false;
+        let mut i = 0;
+
+        while i < lines.len() {
+            let trimmed = lines[i].trim();
+
+            // Skip YAML frontmatter at the top of the file
+            if trimmed == "---" && (i == 0 || in_frontmatter) {
+                in_frontmatter = !in_frontmatter;
+                i += 1;
+                continue;
             }
-            // If only one has a valid time, put the invalid one at the end
-            else if ta.is_some() {
-                std::cmp::Ordering::Less
+            if in_frontmatter {
+                i += 1;
+                continue;
             }
-            else if tb.is_some() {
-                std::cmp::Ordering::Greater
+
+            if trimmed.starts_with('#') {
+                section = Some(trimmed.trim_start_matches('#').trim().to_string());
+                i += 1;
+                continue;
             }
-            // If neither has a valid time, maintain original order
-            else {
-                std::cmp::Ordering::Equal
+
+            // A note spans its first line and any continuation lines below it
+            if let Some(mut note) = bullet.parse_block(&lines[i..], date).or_else(|| table.parse_block(&lines[i..], date)) {
+                note.section = section.clone();
+                note.line_span = i..i + note.line_span.len();
+                i = note.line_span.end;
+                notes.push(note);
+            } else {
+                i += 1;
+            }
+        }
+
+        notes
+    }
+
+    /// Notes within the category's section (or the whole file when no section applies), sorted by time
+    fn notes_in_content(&self, content: &str, date: NaiveDate, category: Option<&str>) -> Result<Vec<Note>, JourneyError> {
+        let mut notes = self.parse_notes(content, date);
+
+        // If section_header is configured, only list notes within that section
+        if let Some(section_name) = self.config.get_section_header(category)? {
+            let lines: Vec<&str> = content.lines().collect();
+            match self.find_section(content, section_name) {
+                Some(section_start) => {
+                    let section_end = self.find_section_end(&lines, section_start);
+                    notes.retain(|note| note.line_span.start > section_start && note.line_span.start < section_end);
+                }
+                // If section doesn't exist, return empty list
+                None => notes.clear(),
             }
-        });
+        }
 
+        // Sort notes by time (ascending chronological), untimed notes last
+        notes.sort_by(|a, b| a.cmp_by_time(b));
         Ok(notes)
     }
 
@@ -432,7 +840,7 @@ impl Vault {
         
         // Sort phrases by length (longest first) to avoid partial replacements
         let mut phrases: Vec<_> = self.config.phrases.iter().collect();
-        phrases.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+        phrases.sort_by_key(|(phrase, _)| std::cmp::Reverse(phrase.len()));
         
         for (phrase, replacement) in phrases {
             // Replace all occurrences of the phrase with its replacement
@@ -443,7 +851,7 @@ impl Vault {
     }
 
     /// Format a custom file path using date components
-    fn format_custom_path(&self, format: &str, date: NaiveDate) -> String {
+    fn format_custom_path(&self, format: &str, date: NaiveDate, week_day: NaiveDate) -> String {
         let year = date.year();
         let month = date.month();
         let day = date.day();
@@ -474,6 +882,13 @@ impl Vault {
         result = result.replace("{day}", &day.to_string());
         result = result.replace("{date}", &day.to_string());
         
+        // Replace ISO week, week-based year and quarter (used by weekly and quarterly notes)
+        let iso_week = week_day.iso_week();
+        result = result.replace("{week_year}", &iso_week.year().to_string());
+        result = result.replace("{week:02}", &format!("{:02}", iso_week.week()));
+        result = result.replace("{week}", &iso_week.week().to_string());
+        result = result.replace("{quarter}", &((month - 1) / 3 + 1).to_string());
+        
         // Replace weekday names (case-sensitive)
         result = result.replace("{Weekday}", &self.format_weekday(weekday, false));
         result = result.replace("{weekday}", &self.format_weekday(weekday, false).to_lowercase());
@@ -509,7 +924,7 @@ impl Vault {
             2 => if short { "Feb".to_string() } else { "February".to_string() },
             3 => if short { "Mar".to_string() } else { "March".to_string() },
             4 => if short { "Apr".to_string() } else { "April".to_string() },
-            5 => if short { "May".to_string() } else { "May".to_string() },
+            5 => "May".to_string(),
             6 => if short { "Jun".to_string() } else { "June".to_string() },
             7 => if short { "Jul".to_string() } else { "July".to_string() },
             8 => if short { "Aug".to_string() } else { "August".to_string() },
@@ -521,103 +936,41 @@ impl Vault {
         }
     }
 
-    /// Format a note entry according to the specified format
-    fn format_note_entry(&self, timestamp: &str, content: &str, format: &NoteFormat) -> String {
-        match format {
-            NoteFormat::Bullet => format!("- [{}] {}\n", timestamp, content),
-            NoteFormat::Table => format!("| {} | {} |\n", timestamp, content),
-        }
-    }
-
-    /// Extract NaiveTime from a note line according to format
-    fn extract_time_from_note_line(&self, line: &str, format: &NoteFormat) -> Option<chrono::NaiveTime> {
-        match format {
-            NoteFormat::Bullet => {
-                // "- [HH:MM[:SS]] content"
-                let trimmed = line.trim_start();
-                if let Some(rest) = trimmed.strip_prefix("- ") {
-                    if let Some(after_bracket) = rest.strip_prefix('[') {
-                        if let Some(closing) = after_bracket.find(']') {
-                            let time_str = &after_bracket[..closing];
-                            chrono::NaiveTime::parse_from_str(time_str, "%H:%M:%S")
-                                .or_else(|_| chrono::NaiveTime::parse_from_str(time_str, "%H:%M")).ok()
-                        } else { None }
-                    } else { None }
-                } else { None }
-            }
-            NoteFormat::Table => {
-                // "| HH:MM | content |"
-                let trimmed = line.trim();
-                if trimmed.starts_with('|') {
-                    let cols: Vec<&str> = trimmed.trim_matches('|').split('|').map(|s| s.trim()).collect();
-                    if let Some(time_str) = cols.get(0) {
-                        chrono::NaiveTime::parse_from_str(time_str, "%H:%M:%S")
-                            .or_else(|_| chrono::NaiveTime::parse_from_str(time_str, "%H:%M")).ok()
-                    } else { None }
-                } else { None }
-            }
-        }
-    }
-
     /// Find insertion index within a section (or whole file when section_start=0) to keep chronological order
-    fn find_insertion_index(&self, lines: &[&str], section_start: usize, new_time_str: &str, format: &NoteFormat) -> usize {
+    fn find_insertion_index(&self, lines: &[&str], section_start: usize, new_time: NaiveDateTime, codec: &NoteCodec, date: NaiveDate) -> usize {
         let section_end = if section_start == 0 { lines.len() } else { self.find_section_end(lines, section_start) };
-        let new_time = chrono::NaiveTime::parse_from_str(new_time_str, "%H:%M:%S")
-            .or_else(|_| chrono::NaiveTime::parse_from_str(new_time_str, "%H:%M")).ok();
 
         // Scan content lines and find first with time greater than new_time
         let mut content_end = section_start + 1;
-        let mut insert_at = None;
-        for i in section_start + 1..section_end {
-            let trimmed = lines[i].trim();
-            if !trimmed.is_empty() { content_end = i + 1; }
-
-            let existing_time_opt = match format {
-                NoteFormat::Bullet => {
-                    if trimmed.starts_with("- ") {
-                        if let Some(rest) = trimmed.strip_prefix("- ") {
-                            if let Some(after_bracket) = rest.strip_prefix('[') {
-                                if let Some(closing) = after_bracket.find(']') {
-                                    let ts = &after_bracket[..closing];
-                                    chrono::NaiveTime::parse_from_str(ts, "%H:%M:%S")
-                                        .or_else(|_| chrono::NaiveTime::parse_from_str(ts, "%H:%M")).ok()
-                                } else { None }
-                            } else { None }
-                        } else { None }
-                    } else { None }
-                }
-                NoteFormat::Table => {
-                    if trimmed.starts_with('|') && !trimmed.starts_with("|---") {
-                        let cols: Vec<&str> = trimmed.trim_matches('|').split('|').map(|s| s.trim()).collect();
-                        cols.get(0).and_then(|ts| chrono::NaiveTime::parse_from_str(ts, "%H:%M:%S")
-                            .or_else(|_| chrono::NaiveTime::parse_from_str(ts, "%H:%M")).ok())
-                    } else { None }
-                }
-            };
+        for (i, line) in lines.iter().enumerate().take(section_end).skip(section_start + 1) {
+            if line.trim().is_empty() {
+                continue;
+            }
+            content_end = i + 1;
 
-            if let (Some(new_t), Some(old_t)) = (new_time, existing_time_opt) {
-                if new_t < old_t {
-                    insert_at = Some(i);
-                    break;
-                }
+            // Continuation lines belong to the note above; never split a block
+            if is_continuation(line) {
+                continue;
+            }
+
+            let existing_time = codec.parse(line, date).and_then(|note| note.datetime());
+            if matches!(existing_time, Some(old_time) if new_time < old_time) {
+                return i;
             }
         }
 
-        insert_at.unwrap_or(content_end)
+        // An empty file has no line after the start to insert behind
+        content_end.min(section_end)
     }
 
     /// Detect the current note format in the content
     pub fn detect_note_format(&self, content: &str) -> Option<NoteFormat> {
-        let lines: Vec<&str> = content.lines().collect();
-        
-        // Look for bullet format
-        let has_bullet_notes = lines.iter().any(|line| line.trim().starts_with("- "));
-        
-        // Look for table format
-        let has_table_notes = lines.iter().any(|line| {
-            let trimmed = line.trim();
-            trimmed.starts_with("|") && trimmed.contains("|") && !trimmed.starts_with("|---")
-        });
+        let bullet = self.codec_for(&NoteFormat::Bullet);
+        let table = self.codec_for(&NoteFormat::Table);
+        let today = self.date_handler.today();
+
+        let has_bullet_notes = content.lines().any(|line| bullet.parse(line, today).is_some());
+        let has_table_notes = content.lines().any(|line| table.parse(line, today).is_some());
         
         if has_bullet_notes && !has_table_notes {
             Some(NoteFormat::Bullet)
@@ -629,7 +982,7 @@ impl Vault {
     }
 
     /// Convert note format if needed
-    fn convert_note_format_if_needed(&self, content: &str, target_format: &NoteFormat) -> Result<String, JourneyError> {
+    fn convert_note_format_if_needed(&self, content: &str, target_format: &NoteFormat, date: NaiveDate) -> Result<String, JourneyError> {
         let current_format = self.detect_note_format(content);
         
         // If no format detected or already matches target, clean up blank lines if it's table format
@@ -640,55 +993,40 @@ impl Vault {
             return Ok(content.to_string());
         }
         
+        let target = self.codec_for(target_format);
+        let source = match target_format {
+            NoteFormat::Bullet => self.codec_for(&NoteFormat::Table),
+            NoteFormat::Table => self.codec_for(&NoteFormat::Bullet),
+        };
         let lines: Vec<&str> = content.lines().collect();
         let mut converted_lines = Vec::new();
         let mut first_note_found = false;
+        let mut i = 0;
         
-        for line in lines {
+        while i < lines.len() {
+            let line = lines[i];
             let trimmed = line.trim();
+            i += 1;
             
-            // Convert bullet to table
-            if target_format == &NoteFormat::Table && trimmed.starts_with("- ") {
+            if let Some(note) = source.parse_block(&lines[i - 1..], date) {
                 // Add table header before the first note
                 if !first_note_found {
-                    let (time_header, content_header) = self.get_table_headers();
-                    converted_lines.push(format!("| {} | {} |", time_header, content_header));
-                    converted_lines.push("|------|----------|".to_string());
+                    converted_lines.extend(target.header_lines());
                     first_note_found = true;
                 }
-                
-                if let Some((timestamp, note_content)) = self.parse_bullet_note(trimmed) {
-                    converted_lines.push(format!("| {} | {} |", timestamp, note_content));
-                } else {
-                    converted_lines.push(line.to_string());
-                }
-            }
-            // Skip blank lines when converting bullet to table
-            else if target_format == &NoteFormat::Table && trimmed.is_empty() && first_note_found {
-                // Skip blank lines between bullet points when converting to table
-                continue;
+                // Multi-line notes convert as a whole, continuation lines included
+                i += note.line_span.len() - 1;
+                converted_lines.push(target.render(&note));
             }
-            // Convert table to bullet
-            else if target_format == &NoteFormat::Bullet && trimmed.starts_with("|") && !trimmed.starts_with("|---") && !trimmed.contains("Time") && !trimmed.contains("Content") {
-                if let Some((timestamp, note_content)) = self.parse_table_note(trimmed) {
-                    converted_lines.push(format!("- [{}] {}", timestamp, note_content));
-                } else {
+            else {
+                // Skip table headers and separators when converting to bullet
+                let is_stale_header = target_format == &NoteFormat::Bullet && source.is_header_line(trimmed);
+                // Skip blank lines between notes: after the first note for tables, everywhere for bullets
+                let is_gap = trimmed.is_empty() && (first_note_found || target_format == &NoteFormat::Bullet);
+                if !is_stale_header && !is_gap {
                     converted_lines.push(line.to_string());
                 }
             }
-            // Skip table headers and separators when converting to bullet
-            else if target_format == &NoteFormat::Bullet && trimmed.starts_with("|") && (trimmed.contains("Time") || trimmed.contains("Content") || trimmed.starts_with("|---")) {
-                // Skip table headers and separators
-                continue;
-            }
-            // Skip blank lines when converting table to bullet
-            else if target_format == &NoteFormat::Bullet && trimmed.is_empty() {
-                // Skip blank lines between table rows when converting to bullet
-                continue;
-            }
-            else {
-                converted_lines.push(line.to_string());
-            }
         }
         
         Ok(converted_lines.join("\n"))
@@ -696,22 +1034,23 @@ impl Vault {
 
     /// Clean up blank lines in table format
     fn clean_table_blank_lines(&self, content: &str) -> String {
-        let lines: Vec<&str> = content.lines().collect();
+        let codec = self.codec_for(&NoteFormat::Table);
+        let today = self.date_handler.today();
         let mut cleaned_lines = Vec::new();
         let mut in_table = false;
         let mut last_was_table_row = false;
         
-        for line in lines {
+        for line in content.lines() {
             let trimmed = line.trim();
             
             // Check if this is a table row
-            if trimmed.starts_with("|") && !trimmed.starts_with("|---") && !trimmed.contains("Time") && !trimmed.contains("Content") {
+            if codec.parse(trimmed, today).is_some() {
                 in_table = true;
                 last_was_table_row = true;
                 cleaned_lines.push(line.to_string());
             }
             // Check if this is a table header or separator
-            else if trimmed.starts_with("|") && (trimmed.contains("Time") || trimmed.contains("Content") || trimmed.starts_with("|---")) {
+            else if codec.is_header_line(trimmed) {
                 in_table = true;
                 last_was_table_row = false;
                 cleaned_lines.push(line.to_string());
@@ -734,38 +1073,54 @@ impl Vault {
         
         cleaned_lines.join("\n")
     }
+}
 
-    /// Parse a bullet note to extract timestamp and content
-    fn parse_bullet_note(&self, line: &str) -> Option<(String, String)> {
-        // Format: "- [timestamp] content"
-        if let Some(after_dash) = line.trim_start().strip_prefix("- ") {
-            if let Some(after_bracket) = after_dash.strip_prefix('[') {
-                if let Some(closing) = after_bracket.find(']') {
-                    let timestamp = after_bracket[..closing].to_string();
-                    let content = after_bracket[closing + 1..].trim().to_string();
-                    return Some((timestamp, content));
-                }
-            }
-        }
-        None
+/// Recursively collect markdown files, skipping hidden directories such as `.obsidian`
+fn collect_markdown_files(dir: &std::path::Path, files: &mut Vec<PathBuf>) -> Result<(), JourneyError> {
+    if !dir.is_dir() {
+        return Ok(());
     }
 
-    /// Parse a table note to extract timestamp and content
-    fn parse_table_note(&self, line: &str) -> Option<(String, String)> {
-        // Format: "| timestamp | content |"
-        let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() >= 3 {
-            let timestamp = parts[1].trim().to_string();
-            let content = parts[2].trim().to_string();
-            return Some((timestamp, content));
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path.file_name().and_then(|n| n.to_str()).is_some_and(|n| n.starts_with('.'));
+        if hidden {
+            continue;
         }
-        None
+        if path.is_dir() {
+            collect_markdown_files(&path, files)?;
+        } else if path.extension().and_then(|e| e.to_str()) == Some("md") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Four-digit runs in a path that could be calendar years
+fn years_in_text(text: &str) -> Vec<i32> {
+    text.split(|c: char| !c.is_ascii_digit())
+        .filter(|run| run.len() == 4)
+        .filter_map(|run| run.parse::<i32>().ok())
+        .filter(|year| (1900..=2200).contains(year))
+        .collect()
+}
+
+/// The `date:` field of a file's YAML frontmatter, if present
+fn frontmatter_date(content: &str) -> Option<NaiveDate> {
+    let mut lines = content.lines();
+    if lines.next()?.trim() != "---" {
+        return None;
     }
+    lines
+        .take_while(|line| line.trim() != "---")
+        .find_map(|line| line.trim().strip_prefix("date:"))
+        .and_then(|value| NaiveDate::parse_from_str(value.trim().trim_matches('"'), "%Y-%m-%d").ok())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Local;
     use std::fs;
     use std::collections::HashMap;
     use tempfile::TempDir;
@@ -795,6 +1150,16 @@ mod tests {
             monthly_format: None,
             quarterly_format: None,
             yearly_format: None,
+            weekly_template: None,
+            monthly_template: None,
+            quarterly_template: None,
+            yearly_template: None,
+            first_day_of_week: None,
+            named_times: HashMap::new(),
+            timezone: None,
+            record_utc_offset: false,
+            day_starts_at: None,
+            sections: std::collections::BTreeMap::new(),
             note_format: None,
         };
 
@@ -808,10 +1173,10 @@ mod tests {
         let date = chrono::Local::now().date_naive();
 
         // Add first note
-        vault.add_note("Test1", None).unwrap();
+        vault.add_note::<Local>("Test1", None).unwrap();
 
         // Add second note
-        vault.add_note("Test2", None).unwrap();
+        vault.add_note::<Local>("Test2", None).unwrap();
 
         // Read the file content
         let note_path = vault.get_note_path(date);
@@ -857,10 +1222,8 @@ mod tests {
                 }
                 in_data_section = true;
                 blank_line_found = false;
-            } else if line.trim().is_empty() {
-                if in_data_section {
-                    blank_line_found = true;
-                }
+            } else if line.trim().is_empty() && in_data_section {
+                blank_line_found = true;
             }
         }
     }
@@ -885,10 +1248,10 @@ date: 2025-10-26
         fs::write(&note_path, existing_content).unwrap();
 
         // Add third note to existing file
-        vault.add_note("Test3", None).unwrap();
+        vault.add_note::<Local>("Test3", None).unwrap();
 
         // Add fourth note to existing file
-        vault.add_note("Test4", None).unwrap();
+        vault.add_note::<Local>("Test4", None).unwrap();
 
         // Read the file content
         let note_path = vault.get_note_path(date);
@@ -934,10 +1297,8 @@ date: 2025-10-26
                 }
                 in_data_section = true;
                 blank_line_found = false;
-            } else if line.trim().is_empty() {
-                if in_data_section {
-                    blank_line_found = true;
-                }
+            } else if line.trim().is_empty() && in_data_section {
+                blank_line_found = true;
             }
         }
     }
@@ -952,7 +1313,7 @@ date: 2025-10-26
         let date = chrono::Local::now().date_naive();
 
         // Add first note - should not create a header
-        vault.add_note("Test1", None).unwrap();
+        vault.add_note::<Local>("Test1", None).unwrap();
 
         // Read the file content
         let note_path = vault.get_note_path(date);
@@ -977,13 +1338,13 @@ date: 2025-10-26
         let date = chrono::Local::now().date_naive();
 
         // Add first note to create table format
-        vault.add_note("Test1", None).unwrap();
+        vault.add_note::<Local>("Test1", None).unwrap();
 
         // Switch to bullet format
         vault.config.list_type = Some(NoteFormat::Bullet);
 
         // Add second note - should convert to bullet format without headers
-        vault.add_note("Test2", None).unwrap();
+        vault.add_note::<Local>("Test2", None).unwrap();
 
         // Read the file content
         let note_path = vault.get_note_path(date);
@@ -1023,7 +1384,7 @@ date: 2025-10-26
         vault.config.list_type = Some(NoteFormat::Table);
 
         // Add third note - should convert to table format without blank lines
-        vault.add_note("Test3", None).unwrap();
+        vault.add_note::<Local>("Test3", None).unwrap();
 
         // Read the file content
         let note_path = vault.get_note_path(date);
@@ -1042,10 +1403,8 @@ date: 2025-10-26
                 }
                 in_table = true;
                 blank_line_found = false;
-            } else if line.trim().is_empty() {
-                if in_table {
-                    blank_line_found = true;
-                }
+            } else if line.trim().is_empty() && in_table {
+                blank_line_found = true;
             }
         }
     }
@@ -1077,7 +1436,7 @@ date: 2025-10-26
         vault.config.list_type = Some(NoteFormat::Bullet);
 
         // Add third note - should convert to bullet format without blank lines
-        vault.add_note("Test3", None).unwrap();
+        vault.add_note::<Local>("Test3", None).unwrap();
 
         // Read the file content
         let note_path = vault.get_note_path(date);
@@ -1096,10 +1455,8 @@ date: 2025-10-26
                 }
                 in_bullet_section = true;
                 blank_line_found = false;
-            } else if line.trim().is_empty() {
-                if in_bullet_section {
-                    blank_line_found = true;
-                }
+            } else if line.trim().is_empty() && in_bullet_section {
+                blank_line_found = true;
             }
         }
     }
@@ -1124,10 +1481,10 @@ date: 2025-10-26
         fs::write(&note_path, existing_content).unwrap();
 
         // Add third note to existing file - should not create blank lines
-        vault.add_note("Test3", None).unwrap();
+        vault.add_note::<Local>("Test3", None).unwrap();
 
         // Add fourth note to existing file - should not create blank lines
-        vault.add_note("Test4", None).unwrap();
+        vault.add_note::<Local>("Test4", None).unwrap();
 
         // Read the file content
         let note_path = vault.get_note_path(date);
@@ -1149,10 +1506,8 @@ date: 2025-10-26
                 }
                 in_notes_section = true;
                 blank_line_found = false;
-            } else if line.trim().is_empty() {
-                if in_notes_section {
-                    blank_line_found = true;
-                }
+            } else if line.trim().is_empty() && in_notes_section {
+                blank_line_found = true;
             }
         }
     }
@@ -1176,7 +1531,7 @@ date: 2025-10-26
         fs::write(&note_path, existing_content).unwrap();
 
         // Add third note to existing file - this should trigger the append logic
-        vault.add_note("Test3", None).unwrap();
+        vault.add_note::<Local>("Test3", None).unwrap();
 
         // Read the file content
         let note_path = vault.get_note_path(date);
@@ -1195,10 +1550,8 @@ date: 2025-10-26
                 }
                 in_notes_section = true;
                 blank_line_found = false;
-            } else if line.trim().is_empty() {
-                if in_notes_section {
-                    blank_line_found = true;
-                }
+            } else if line.trim().is_empty() && in_notes_section {
+                blank_line_found = true;
             }
         }
     }
@@ -1231,7 +1584,7 @@ Some other content
         fs::write(&note_path, existing_content).unwrap();
 
         // Add third note to the section - should not create blank lines
-        vault.add_note("Test3", None).unwrap();
+        vault.add_note::<Local>("Test3", None).unwrap();
 
         // Read the file content
         let note_path = vault.get_note_path(date);
@@ -1253,7 +1606,6 @@ Some other content
                 continue;
             }
             if *line == "# Another Section" {
-                in_section = false;
                 break;
             }
             if in_section && line.starts_with("|") && !line.starts_with("|---") && !line.contains("Time") && !line.contains("Content") {
@@ -1276,8 +1628,8 @@ Some other content
         let date = chrono::Local::now().date_naive();
 
         // Add some notes in table format
-        vault.add_note("Test1", None).unwrap();
-        vault.add_note("Test2", None).unwrap();
+        vault.add_note::<Local>("Test1", None).unwrap();
+        vault.add_note::<Local>("Test2", None).unwrap();
 
         // Test listing without header flag
         let notes = vault.list_notes(date).unwrap();
@@ -1300,10 +1652,10 @@ Some other content
         let date = chrono::Local::now().date_naive();
 
         // Add first note
-        vault.add_note("Test1", None).unwrap();
+        vault.add_note::<Local>("Test1", None).unwrap();
 
         // Add second note
-        vault.add_note("Test2", None).unwrap();
+        vault.add_note::<Local>("Test2", None).unwrap();
 
         // Read the file content
         let note_path = vault.get_note_path(date);
@@ -1349,10 +1701,8 @@ Some other content
                 }
                 in_table = true;
                 blank_line_found = false;
-            } else if line.trim().is_empty() {
-                if in_table {
-                    blank_line_found = true;
-                }
+            } else if line.trim().is_empty() && in_table {
+                blank_line_found = true;
             }
         }
     }
@@ -1,12 +1,13 @@
 use journey::app::{App, CliArgs};
+use journey::cli::{Cli, DateRangeArgs};
 use journey::config::{Config, VaultConfig};
 use journey::config_manager::ConfigManager;
-use std::collections::HashMap;
 use std::path::PathBuf;
 use tempfile::TempDir;
 use std::env;
 use chrono::{NaiveDate, Local};
 use serial_test::serial;
+use clap::Parser;
 
 // Safety mechanism: Ensure tests never touch production config
 fn ensure_test_isolation() {
@@ -448,3 +449,117 @@ fn test_unlist_default_vault() {
     assert!(!app.get_config().vaults.contains_key("vault1"));
     assert!(app.get_config().vaults.contains_key("vault2"));
 }
+
+#[test]
+#[serial]
+fn test_resolve_date_range() {
+    let mut config = Config::new();
+    config.add_vault(VaultConfig::test_config("test", "/tmp/test-vault"));
+    let (app, _cleanup) = create_app_with_config(config);
+
+    let mut cli_args = CliArgs {
+        vault: None,
+        date: Some("2025-10-24".to_string()),
+        relative_date: None,
+        time: None,
+        time_format: None,
+    };
+
+    // No range flags: single-day listing
+    assert_eq!(app.resolve_date_range(&cli_args, &DateRangeArgs::default()).unwrap(), None);
+
+    // --week is relative to --date
+    let week = DateRangeArgs { week: true, ..Default::default() };
+    let range = app.resolve_date_range(&cli_args, &week).unwrap().unwrap();
+    assert_eq!(range.start, NaiveDate::from_ymd_opt(2025, 10, 20).unwrap());
+    assert_eq!(range.end, NaiveDate::from_ymd_opt(2025, 10, 26).unwrap());
+
+    // --from/--to
+    let explicit = DateRangeArgs {
+        from: Some("2025-10-01".to_string()),
+        to: Some("2025-10-15".to_string()),
+        ..Default::default()
+    };
+    let range = app.resolve_date_range(&cli_args, &explicit).unwrap().unwrap();
+    assert_eq!(range.days().count(), 15);
+
+    // START..END in --date
+    cli_args.date = Some("2025-10-01..2025-10-03".to_string());
+    let range = app.resolve_date_range(&cli_args, &DateRangeArgs::default()).unwrap().unwrap();
+    assert_eq!(range.end, NaiveDate::from_ymd_opt(2025, 10, 3).unwrap());
+
+    // Ranges are rejected where a single date is expected
+    assert!(app.parse_date(&cli_args).is_err());
+}
+
+#[test]
+#[serial]
+fn test_journey_now_pins_the_clock() {
+    let mut config = Config::new();
+    config.add_vault(VaultConfig::test_config("test", "/tmp/test-vault"));
+
+    env::set_var("JOURNEY_NOW", "2025-10-24T14:30:00");
+    let (app, _cleanup) = create_app_with_config(config);
+    env::remove_var("JOURNEY_NOW");
+
+    let today = CliArgs { vault: None, date: None, relative_date: None, time: None, time_format: None };
+    assert_eq!(app.parse_date(&today).unwrap(), NaiveDate::from_ymd_opt(2025, 10, 24).unwrap());
+    let yesterday = CliArgs { relative_date: Some(1), ..today.clone() };
+    assert_eq!(app.parse_date(&yesterday).unwrap(), NaiveDate::from_ymd_opt(2025, 10, 23).unwrap());
+
+    // A clock set in code takes over from the environment
+    let app = app.with_clock("2025-01-01T09:00:00".parse().unwrap());
+    assert_eq!(app.parse_date(&today).unwrap(), NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+
+    env::set_var("JOURNEY_NOW", "not a time");
+    let result = App::new();
+    env::remove_var("JOURNEY_NOW");
+    assert!(result.is_err());
+}
+
+#[test]
+#[serial]
+fn test_at_accepts_a_bare_time() {
+    let mut config = Config::new();
+    config.add_vault(VaultConfig::test_config("test", "/tmp/test-vault"));
+    let (app, _cleanup) = create_app_with_config(config);
+    let mut app = app.with_clock("2025-10-24T14:30:00".parse().unwrap());
+
+    app.run(Cli::parse_from(["journey", "--at", "16:45", "Call with the landlord"])).unwrap();
+    app.run(Cli::parse_from(["journey", "--at", "now", "Right now"])).unwrap();
+    app.run(Cli::parse_from(["journey", "--at", "yesterday 9:00", "Yesterday morning"])).unwrap();
+
+    let vault = app.get_vault(None).unwrap();
+    let today = vault.list_notes(NaiveDate::from_ymd_opt(2025, 10, 24).unwrap()).unwrap();
+    assert!(today.iter().any(|note| note.contains("16:45") && note.contains("Call with the landlord")));
+    assert!(today.iter().any(|note| note.contains("14:30") && note.contains("Right now")));
+    let yesterday = vault.list_notes(NaiveDate::from_ymd_opt(2025, 10, 23).unwrap()).unwrap();
+    assert!(yesterday.iter().any(|note| note.contains("09:00") && note.contains("Yesterday morning")));
+
+    assert!(app.run(Cli::parse_from(["journey", "--at", "someday 16:45", "Nope"])).is_err());
+}
+
+#[test]
+#[serial]
+fn test_import_calendar_follows_day_starts_at() {
+    let mut config = Config::new();
+    let mut vault_config = VaultConfig::test_config("test", "/tmp/test-vault");
+    vault_config.day_starts_at = Some("04:00".parse().unwrap());
+    config.add_vault(vault_config);
+    let (mut app, _cleanup) = create_app_with_config(config);
+
+    let ics_dir = TempDir::new().unwrap();
+    let ics = ics_dir.path().join("work.ics");
+    std::fs::write(&ics, "BEGIN:VCALENDAR\n\
+        BEGIN:VEVENT\nDTSTART:20251024T020000\nSUMMARY:Night before\nEND:VEVENT\n\
+        BEGIN:VEVENT\nDTSTART:20251024T090000\nSUMMARY:Standup\nEND:VEVENT\n\
+        BEGIN:VEVENT\nDTSTART:20251025T020000\nSUMMARY:Late deploy\nEND:VEVENT\n\
+        END:VCALENDAR\n").unwrap();
+
+    app.run(Cli::parse_from(["journey", "import-calendar", ics.to_str().unwrap(), "--date", "2025-10-24"])).unwrap();
+
+    let vault = app.get_vault(None).unwrap();
+    let notes = vault.list_notes(NaiveDate::from_ymd_opt(2025, 10, 24).unwrap()).unwrap();
+    assert_eq!(notes, vec!["- [09:00:00] Standup", "- [26:00:00] Late deploy"]);
+    assert!(!vault.get_note_path(NaiveDate::from_ymd_opt(2025, 10, 25).unwrap()).exists());
+}
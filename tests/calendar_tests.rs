@@ -0,0 +1,64 @@
+mod common;
+
+use common::{configured_vault, date};
+use journey::calendar::{day_counts, render_heatmap, render_month};
+use journey::date_time::{DateRange, Period};
+use chrono::Weekday;
+use std::collections::BTreeMap;
+
+#[test]
+fn test_day_counts_follow_custom_file_path_format() {
+    let (vault, _temp_dir) = configured_vault(|config| {
+        config.file_path_format = Some("{year}/{month:02}/{date:02}.md".to_string());
+    }, &[
+        (date(2025, 10, 2), "- [09:00:00] One\n- [10:00:00] Two\n"),
+        (date(2025, 10, 3), "# Empty day\n"),
+    ]);
+
+    let counts = day_counts(&vault, DateRange::month_of(date(2025, 10, 1))).unwrap();
+    assert_eq!(counts, BTreeMap::from([(date(2025, 10, 2), 2), (date(2025, 10, 3), 0)]));
+}
+
+#[test]
+fn test_month_calendar_layout() {
+    let counts = BTreeMap::from([(date(2025, 10, 1), 1), (date(2025, 10, 21), 12)]);
+
+    let monday = render_month(2025, 10, &counts, Weekday::Mon, false);
+    let lines: Vec<&str> = monday.lines().collect();
+    assert_eq!(lines[0].trim(), "October 2025");
+    assert_eq!(lines[1], "Mo     Tu     We     Th     Fr     Sa     Su");
+    assert_eq!(lines[2], "               1(1)   2      3      4      5");
+    assert!(lines[5].starts_with("20     21(12) 22"));
+    assert_eq!(lines.len(), 7);
+
+    // October 1st 2025 is a Wednesday: three leading cells from Sunday
+    let sunday = render_month(2025, 10, &counts, Weekday::Sun, false);
+    let lines: Vec<&str> = sunday.lines().collect();
+    assert_eq!(lines[1], "Su     Mo     Tu     We     Th     Fr     Sa");
+    assert!(lines[2].starts_with("                      1(1)"));
+    assert!(lines[2].ends_with(" 4"));
+
+    assert!(render_month(2025, 10, &counts, Weekday::Mon, true).contains("\x1b[7m 1\x1b[0m(1)"));
+}
+
+#[test]
+fn test_heatmap_grid() {
+    let range = Period::Year.range(date(2025, 6, 1), Weekday::Mon);
+    let counts = BTreeMap::from([(date(2025, 1, 1), 1), (date(2025, 1, 2), 4), (date(2025, 1, 6), 0)]);
+
+    let heatmap = render_heatmap(range, &counts, Weekday::Mon);
+    let lines: Vec<&str> = heatmap.lines().collect();
+
+    assert!(lines[0].trim_start().starts_with("Jan"));
+    assert!(lines[0].contains("Dec"));
+    // Monday row: Dec 30 and 31 2024 fall outside the year, Jan 6 has a file without notes
+    assert!(lines[1].starts_with("Mon   ·"));
+    // Wednesday Jan 1 is light, Thursday Jan 2 is the busiest day
+    assert!(lines[3].starts_with("Wed ░"));
+    assert!(lines[4].starts_with("    █"));
+    assert_eq!(lines.iter().filter(|l| l.starts_with("Sun")).count(), 1);
+    assert!(heatmap.contains("5 notes on 2 days"));
+
+    let sunday_first = render_heatmap(range, &counts, Weekday::Sun);
+    assert!(sunday_first.lines().nth(1).unwrap().starts_with("Sun"));
+}
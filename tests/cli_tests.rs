@@ -1,10 +1,9 @@
-use journey::cli::{Cli, Commands};
+use journey::cli::{Cli, Commands, ExportArgs};
 use clap::Parser;
-use std::path::PathBuf;
 
 #[test]
 fn test_cli_parse_default_behavior() {
-    let cli = Cli::try_parse_from(&["journey", "test note"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "test note"]).unwrap();
     assert_eq!(cli.note_content, vec!["test note"]);
     assert!(cli.command.is_none());
     assert!(!cli.list);
@@ -13,61 +12,61 @@ fn test_cli_parse_default_behavior() {
 
 #[test]
 fn test_cli_parse_list_command() {
-    let cli = Cli::try_parse_from(&["journey", "--list"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "--list"]).unwrap();
     assert!(cli.list);
     assert!(cli.command.is_none());
 }
 
 #[test]
 fn test_cli_parse_edit_command() {
-    let cli = Cli::try_parse_from(&["journey", "--edit"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "--edit"]).unwrap();
     assert!(cli.edit);
     assert!(cli.command.is_none());
 }
 
 #[test]
 fn test_cli_parse_with_vault() {
-    let cli = Cli::try_parse_from(&["journey", "--vault", "test", "note"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "--vault", "test", "note"]).unwrap();
     assert_eq!(cli.vault, Some("test".to_string()));
     assert_eq!(cli.note_content, vec!["note"]);
 }
 
 #[test]
 fn test_cli_parse_version_short() {
-    let cli = Cli::try_parse_from(&["journey", "-v"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "-v"]).unwrap();
     assert!(cli.version);
 }
 
 #[test]
 fn test_cli_parse_version_long() {
-    let cli = Cli::try_parse_from(&["journey", "--version"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "--version"]).unwrap();
     assert!(cli.version);
 }
 
 #[test]
 fn test_cli_parse_with_date() {
-    let cli = Cli::try_parse_from(&["journey", "--date", "2025-10-24", "note"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "--date", "2025-10-24", "note"]).unwrap();
     assert_eq!(cli.date, Some("2025-10-24".to_string()));
     assert_eq!(cli.note_content, vec!["note"]);
 }
 
 #[test]
 fn test_cli_parse_with_relative_date() {
-    let cli = Cli::try_parse_from(&["journey", "--relative-date", "1", "note"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "--relative-date", "1", "note"]).unwrap();
     assert_eq!(cli.relative_date, Some(1));
     assert_eq!(cli.note_content, vec!["note"]);
 }
 
 #[test]
 fn test_cli_parse_with_time() {
-    let cli = Cli::try_parse_from(&["journey", "--time", "14:30", "note"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "--time", "14:30", "note"]).unwrap();
     assert_eq!(cli.time, Some("14:30".to_string()));
     assert_eq!(cli.note_content, vec!["note"]);
 }
 
 #[test]
 fn test_cli_parse_add_command() {
-    let cli = Cli::try_parse_from(&["journey", "add", "test note"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "add", "test note"]).unwrap();
     match cli.command {
         Some(Commands::Add { content }) => {
             assert_eq!(content, "test note");
@@ -78,7 +77,7 @@ fn test_cli_parse_add_command() {
 
 #[test]
 fn test_cli_parse_list_command_subcommand() {
-    let cli = Cli::try_parse_from(&["journey", "list"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "list"]).unwrap();
     match cli.command {
         Some(Commands::List) => {
             // Expected
@@ -89,7 +88,7 @@ fn test_cli_parse_list_command_subcommand() {
 
 #[test]
 fn test_cli_parse_edit_command_subcommand() {
-    let cli = Cli::try_parse_from(&["journey", "edit"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "edit"]).unwrap();
     match cli.command {
         Some(Commands::Edit) => {
             // Expected
@@ -100,7 +99,7 @@ fn test_cli_parse_edit_command_subcommand() {
 
 #[test]
 fn test_cli_parse_stdin_flag() {
-    let cli = Cli::try_parse_from(&["journey", "--stdin"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "--stdin"]).unwrap();
     assert!(cli.stdin);
     assert!(cli.command.is_none());
     assert!(!cli.list);
@@ -109,21 +108,281 @@ fn test_cli_parse_stdin_flag() {
 
 #[test]
 fn test_cli_parse_stdin_with_time() {
-    let cli = Cli::try_parse_from(&["journey", "--stdin", "--time", "14:30"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "--stdin", "--time", "14:30"]).unwrap();
     assert!(cli.stdin);
     assert_eq!(cli.time, Some("14:30".to_string()));
 }
 
 #[test]
 fn test_cli_parse_stdin_with_date() {
-    let cli = Cli::try_parse_from(&["journey", "--stdin", "--date", "2025-10-24"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "--stdin", "--date", "2025-10-24"]).unwrap();
     assert!(cli.stdin);
     assert_eq!(cli.date, Some("2025-10-24".to_string()));
 }
 
 #[test]
 fn test_cli_parse_stdin_with_vault() {
-    let cli = Cli::try_parse_from(&["journey", "--stdin", "--vault", "test-vault"]).unwrap();
+    let cli = Cli::try_parse_from(["journey", "--stdin", "--vault", "test-vault"]).unwrap();
     assert!(cli.stdin);
     assert_eq!(cli.vault, Some("test-vault".to_string()));
 }
+
+#[test]
+fn test_cli_parse_stdin_single() {
+    let cli = Cli::try_parse_from(["journey", "--stdin-single"]).unwrap();
+    assert!(cli.stdin_single);
+    assert!(!cli.stdin);
+
+    let cli = Cli::try_parse_from(["journey", "--multiline", "--time", "14:30"]).unwrap();
+    assert!(cli.stdin_single);
+
+    assert!(Cli::try_parse_from(["journey", "--stdin", "--multiline"]).is_err());
+}
+
+#[test]
+fn test_cli_parse_list_with_range() {
+    let cli = Cli::try_parse_from(["journey", "--list", "--from", "2025-10-01", "--to", "2025-10-15"]).unwrap();
+    assert!(cli.list);
+    assert_eq!(cli.range.from, Some("2025-10-01".to_string()));
+    assert_eq!(cli.range.to, Some("2025-10-15".to_string()));
+
+    let cli = Cli::try_parse_from(["journey", "--list", "--last", "7"]).unwrap();
+    assert_eq!(cli.range.last, Some(7));
+
+    let cli = Cli::try_parse_from(["journey", "--list", "--week"]).unwrap();
+    assert!(cli.range.week);
+}
+
+#[test]
+fn test_cli_parse_range_conflicts() {
+    assert!(Cli::try_parse_from(["journey", "--list", "--week", "--month"]).is_err());
+    assert!(Cli::try_parse_from(["journey", "--list", "--from", "2025-10-01", "--last", "3"]).is_err());
+    assert!(Cli::try_parse_from(["journey", "--list", "--to", "2025-10-01"]).is_err());
+}
+
+#[test]
+fn test_cli_parse_list_with_tag() {
+    let cli = Cli::try_parse_from(["journey", "--list", "--tag", "deploy"]).unwrap();
+    assert_eq!(cli.tag, Some("deploy".to_string()));
+}
+
+#[test]
+fn test_cli_parse_search_and_tags() {
+    let cli = Cli::try_parse_from(["journey", "search", "--tag", "deploy"]).unwrap();
+    match cli.command {
+        Some(Commands::Search { query, tag, .. }) => {
+            assert_eq!(query, None);
+            assert_eq!(tag, Some("deploy".to_string()));
+        }
+        _ => panic!("Expected search command"),
+    }
+
+    // A query or a tag is required
+    assert!(Cli::try_parse_from(["journey", "search"]).is_err());
+
+    let cli = Cli::try_parse_from(["journey", "tags", "--last", "30"]).unwrap();
+    assert!(matches!(cli.command, Some(Commands::Tags { .. })));
+}
+
+#[test]
+fn test_cli_parse_rm_and_amend() {
+    let cli = Cli::try_parse_from(["journey", "rm", "--date", "2025-10-24", "--at", "14:30"]).unwrap();
+    match cli.command {
+        Some(Commands::Rm { selector }) => {
+            assert_eq!(selector.date, Some("2025-10-24".to_string()));
+            assert_eq!(selector.at, Some("14:30".to_string()));
+        }
+        _ => panic!("Expected rm command"),
+    }
+
+    let cli = Cli::try_parse_from(["journey", "amend", "--last", "fixed", "typo"]).unwrap();
+    match cli.command {
+        Some(Commands::Amend { selector, content }) => {
+            assert!(selector.last);
+            assert_eq!(content, vec!["fixed", "typo"]);
+        }
+        _ => panic!("Expected amend command"),
+    }
+
+    // Exactly one selector is required
+    assert!(Cli::try_parse_from(["journey", "rm"]).is_err());
+    assert!(Cli::try_parse_from(["journey", "rm", "--last", "--index", "2"]).is_err());
+}
+
+#[test]
+fn test_cli_parse_mv() {
+    let cli = Cli::try_parse_from(["journey", "mv", "--at", "14:30", "--to-date", "yesterday", "--to-vault", "work"]).unwrap();
+    match cli.command {
+        Some(Commands::Mv { selector, to_date, to_time, to_category, to_vault }) => {
+            assert_eq!(selector.at, Some("14:30".to_string()));
+            assert_eq!(to_date, Some("yesterday".to_string()));
+            assert_eq!(to_time, None);
+            assert_eq!(to_category, None);
+            assert_eq!(to_vault, Some("work".to_string()));
+        }
+        _ => panic!("Expected mv command"),
+    }
+
+    // A selector and at least one target are required
+    assert!(Cli::try_parse_from(["journey", "mv", "--last"]).is_err());
+    assert!(Cli::try_parse_from(["journey", "mv", "--to-date", "today"]).is_err());
+}
+
+#[test]
+fn test_cli_parse_task_todo_done() {
+    let cli = Cli::try_parse_from(["journey", "--task", "Fix flaky test"]).unwrap();
+    assert!(cli.task);
+    assert_eq!(cli.note_content, vec!["Fix flaky test"]);
+
+    let cli = Cli::try_parse_from(["journey", "todo", "--last", "7", "-c", "work"]).unwrap();
+    match cli.command {
+        Some(Commands::Todo { category, range, .. }) => {
+            assert_eq!(category, Some("work".to_string()));
+            assert_eq!(range.last, Some(7));
+        }
+        _ => panic!("Expected todo command"),
+    }
+
+    let cli = Cli::try_parse_from(["journey", "done", "--date", "2025-10-24", "--at", "14:30"]).unwrap();
+    assert!(matches!(cli.command, Some(Commands::Done { .. })));
+    assert!(Cli::try_parse_from(["journey", "done"]).is_err());
+}
+
+#[test]
+fn test_cli_parse_export() {
+    let cli = Cli::try_parse_from(["journey", "export", "--format", "csv", "--month", "-o", "out.csv"]).unwrap();
+    match cli.command {
+        Some(Commands::Export { options }) => {
+            assert_eq!(options.format, journey::export::ExportFormat::Csv);
+            assert!(options.range.month);
+            assert_eq!(options.output, Some(std::path::PathBuf::from("out.csv")));
+        }
+        _ => panic!("Expected export command"),
+    }
+
+    let cli = Cli::try_parse_from(["journey", "export"]).unwrap();
+    assert!(matches!(cli.command, Some(Commands::Export { options: ExportArgs { format: journey::export::ExportFormat::Json, .. } })));
+    assert!(Cli::try_parse_from(["journey", "export", "--format", "xml"]).is_err());
+
+    let cli = Cli::try_parse_from(["journey", "export", "--format", "ics", "--duration", "15"]).unwrap();
+    assert!(matches!(cli.command, Some(Commands::Export { options: ExportArgs { format: journey::export::ExportFormat::Ics, duration: Some(15), .. } })));
+}
+
+#[test]
+fn test_cli_parse_import() {
+    let cli = Cli::try_parse_from(["journey", "import", "--format", "ndjson", "backfill.ndjson", "-V", "work"]).unwrap();
+    match cli.command {
+        Some(Commands::Import { format, file, vault }) => {
+            assert_eq!(format, journey::import::ImportFormat::Ndjson);
+            assert_eq!(file, std::path::PathBuf::from("backfill.ndjson"));
+            assert_eq!(vault, Some("work".to_string()));
+        }
+        _ => panic!("Expected import command"),
+    }
+
+    assert!(Cli::try_parse_from(["journey", "import", "backfill.csv"]).is_err());
+}
+
+#[test]
+fn test_cli_parse_import_calendar() {
+    let cli = Cli::try_parse_from(["journey", "import-calendar", "work.ics", "--date", "2025-10-24"]).unwrap();
+    match cli.command {
+        Some(Commands::ImportCalendar { file, date, vault }) => {
+            assert_eq!(file, std::path::PathBuf::from("work.ics"));
+            assert_eq!(date, Some("2025-10-24".to_string()));
+            assert_eq!(vault, None);
+        }
+        _ => panic!("Expected import-calendar command"),
+    }
+}
+
+#[test]
+fn test_cli_parse_period() {
+    let cli = Cli::try_parse_from(["journey", "--period", "week", "Plan the sprint"]).unwrap();
+    assert_eq!(cli.period, Some(journey::date_time::Period::Week));
+    assert_eq!(cli.note_content, vec!["Plan the sprint"]);
+
+    let cli = Cli::try_parse_from(["journey", "--list", "--period", "quarter", "-d", "2025-11-03"]).unwrap();
+    assert!(cli.list);
+    assert_eq!(cli.period, Some(journey::date_time::Period::Quarter));
+
+    assert!(Cli::try_parse_from(["journey", "--period", "fortnight", "x"]).is_err());
+    assert!(Cli::try_parse_from(["journey", "--list", "--period", "week", "--month"]).is_err());
+}
+
+#[test]
+fn test_cli_parse_digest() {
+    let cli = Cli::try_parse_from(["journey", "digest", "--week", "--date", "2025-10-22", "--write"]).unwrap();
+    match cli.command {
+        Some(Commands::Digest { week, month, date, vault, write }) => {
+            assert!(week && !month && write);
+            assert_eq!(date, Some("2025-10-22".to_string()));
+            assert_eq!(vault, None);
+        }
+        _ => panic!("Expected digest command"),
+    }
+
+    assert!(Cli::try_parse_from(["journey", "digest"]).is_err());
+    assert!(Cli::try_parse_from(["journey", "digest", "--week", "--month"]).is_err());
+}
+
+#[test]
+fn test_cli_parse_stats() {
+    let cli = Cli::try_parse_from(["journey", "stats", "--from", "2025-01-01", "--json"]).unwrap();
+    match cli.command {
+        Some(Commands::Stats { vault, json, range }) => {
+            assert_eq!(vault, None);
+            assert!(json);
+            assert_eq!(range.from, Some("2025-01-01".to_string()));
+        }
+        _ => panic!("Expected stats command"),
+    }
+}
+
+#[test]
+fn test_cli_parse_cal_and_heatmap() {
+    let cli = Cli::try_parse_from(["journey", "cal", "--month", "2025-10"]).unwrap();
+    assert!(matches!(cli.command, Some(Commands::Cal { month: Some(ref m), vault: None }) if m == "2025-10"));
+
+    let cli = Cli::try_parse_from(["journey", "heatmap", "--year"]).unwrap();
+    assert!(matches!(cli.command, Some(Commands::Heatmap { year: Some(None), .. })));
+
+    let cli = Cli::try_parse_from(["journey", "heatmap", "--year", "2024"]).unwrap();
+    assert!(matches!(cli.command, Some(Commands::Heatmap { year: Some(Some(2024)), .. })));
+}
+
+#[test]
+fn test_cli_parse_onthisday() {
+    let cli = Cli::try_parse_from(["journey", "onthisday", "--date", "2024-02-29", "--recent", "-c", "work"]).unwrap();
+    match cli.command {
+        Some(Commands::Onthisday { date, recent, category, vault }) => {
+            assert_eq!(date, Some("2024-02-29".to_string()));
+            assert!(recent);
+            assert_eq!(category, Some("work".to_string()));
+            assert_eq!(vault, None);
+        }
+        _ => panic!("Expected onthisday command"),
+    }
+}
+
+#[test]
+fn test_cli_parse_at() {
+    let cli = Cli::try_parse_from(["journey", "--at", "yesterday 16:45", "Call with Bob"]).unwrap();
+    assert_eq!(cli.at, Some("yesterday 16:45".to_string()));
+    assert_eq!(cli.note_content, vec!["Call with Bob"]);
+
+    let cli = Cli::try_parse_from(["journey", "-t", "2h ago", "Standup"]).unwrap();
+    assert_eq!(cli.time, Some("2h ago".to_string()));
+    let cli = Cli::try_parse_from(["journey", "-t", "-20m", "Standup"]).unwrap();
+    assert_eq!(cli.time, Some("-20m".to_string()));
+
+    assert!(Cli::try_parse_from(["journey", "--at", "yesterday 16:45", "-t", "10:00", "x"]).is_err());
+    assert!(Cli::try_parse_from(["journey", "--at", "yesterday 16:45", "-d", "today", "x"]).is_err());
+}
+
+#[test]
+fn test_cli_parse_tz() {
+    let cli = Cli::try_parse_from(["journey", "--tz", "Asia/Tokyo", "Landed"]).unwrap();
+    assert_eq!(cli.tz.map(|zone| zone.to_string()), Some("Asia/Tokyo".to_string()));
+    assert!(Cli::try_parse_from(["journey", "--tz", "Atlantis", "x"]).is_err());
+}
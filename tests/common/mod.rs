@@ -0,0 +1,26 @@
+//! Fixtures shared by the integration tests
+#![allow(dead_code)]
+
+use journey::config::VaultConfig;
+use journey::vault::Vault;
+use chrono::NaiveDate;
+use std::fs;
+use tempfile::TempDir;
+
+pub fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+/// A vault named "journal" in a temporary directory, adjusted by `configure`, with the given daily files
+pub fn configured_vault(configure: impl FnOnce(&mut VaultConfig), days: &[(NaiveDate, &str)]) -> (Vault, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = VaultConfig::test_config("journal", temp_dir.path().to_str().unwrap());
+    configure(&mut config);
+    let vault = Vault::new(config);
+    for (day, content) in days {
+        let path = vault.get_note_path(*day);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    (vault, temp_dir)
+}
@@ -1,8 +1,6 @@
 use journey::config_manager::ConfigManager;
 use journey::config::{Config, VaultConfig};
-use std::collections::HashMap;
 use tempfile::TempDir;
-use std::path::PathBuf;
 
 fn create_test_config_manager() -> (ConfigManager, TempDir) {
     let temp_dir = TempDir::new().unwrap();
@@ -1,5 +1,4 @@
 use journey::config::{Config, VaultConfig};
-use std::collections::HashMap;
 use std::path::PathBuf;
 use serial_test::serial;
 
@@ -485,3 +484,168 @@ vaults:
     // The template_file should remain unchanged
     assert_eq!(vault.template_file, Some("/absolute/path/to/template.md".to_string()));
 }
+
+#[test]
+fn test_first_day_of_week() {
+    let yaml_content = r#"
+vaults:
+  test:
+    name: test
+    path: /tmp/journal
+    locale: en-US
+    phrases: {}
+    template_file: null
+    first_day_of_week: sunday
+"#;
+
+    let config: Config = serde_yaml_ng::from_str(yaml_content).unwrap();
+    assert_eq!(config.get_vault("test").unwrap().week_starts_on(), chrono::Weekday::Sun);
+    assert_eq!(VaultConfig::test_config("test", "/tmp/test").week_starts_on(), chrono::Weekday::Mon);
+}
+
+#[test]
+fn test_named_times() {
+    let yaml_content = r#"
+vaults:
+  test:
+    name: test
+    path: /tmp/journal
+    locale: en-US
+    phrases: {}
+    template_file: null
+    named_times:
+      lunch: "11:30"
+"#;
+
+    let config: Config = serde_yaml_ng::from_str(yaml_content).unwrap();
+    assert_eq!(config.get_vault("test").unwrap().named_times.get("lunch"), Some(&"11:30".to_string()));
+    assert!(VaultConfig::test_config("test", "/tmp/test").named_times.is_empty());
+}
+
+#[test]
+fn test_timezone_settings() {
+    let yaml_content = r#"
+vaults:
+  test:
+    name: test
+    path: /tmp/journal
+    locale: en-US
+    phrases: {}
+    template_file: null
+    timezone: Europe/Oslo
+    record_utc_offset: true
+"#;
+
+    let config: Config = serde_yaml_ng::from_str(yaml_content).unwrap();
+    let vault = config.get_vault("test").unwrap();
+    assert_eq!(vault.timezone.map(|zone| zone.to_string()), Some("Europe/Oslo".to_string()));
+    assert!(vault.record_utc_offset);
+
+    let bad = yaml_content.replace("Europe/Oslo", "Nowhere/Special");
+    assert!(serde_yaml_ng::from_str::<Config>(&bad).is_err());
+}
+
+#[test]
+fn test_day_starts_at() {
+    let yaml_content = r#"
+vaults:
+  test:
+    name: test
+    path: /tmp/journal
+    locale: en-US
+    phrases: {}
+    template_file: null
+    day_starts_at: "04:00"
+"#;
+
+    let config: Config = serde_yaml_ng::from_str(yaml_content).unwrap();
+    let vault = config.get_vault("test").unwrap();
+    assert_eq!(vault.day_starts_at, chrono::NaiveTime::from_hms_opt(4, 0, 0));
+
+    let bad = yaml_content.replace("04:00", "late");
+    assert!(serde_yaml_ng::from_str::<Config>(&bad).is_err());
+}
+
+#[test]
+fn test_sections() {
+    let yaml_content = r#"
+vaults:
+  test:
+    name: test
+    path: /tmp/journal
+    locale: en-US
+    phrases: {}
+    template_file: null
+    section_header_health: "Health"
+    sections:
+      work:
+        header: "Work"
+        aliases: [job, office]
+        level: 2
+        order: 1
+      reading: "Books"
+"#;
+
+    let config: Config = serde_yaml_ng::from_str(yaml_content).unwrap();
+    let vault = config.get_vault("test").unwrap();
+
+    // Legacy fields are migrated into sections
+    assert_eq!(vault.section_header_health, None);
+    assert_eq!(vault.sections["health"].header, "Health");
+    assert_eq!(vault.sections["reading"].header, "Books");
+
+    // Ordered categories first, then by name
+    let order: Vec<&str> = vault.categories().into_iter().map(|(category, _)| category).collect();
+    assert_eq!(order, vec!["work", "health", "reading"]);
+
+    assert_eq!(vault.get_section_header(Some("Job")).unwrap().map(String::as_str), Some("Work"));
+    assert_eq!(vault.section_level(Some("office")), 2);
+    assert_eq!(vault.section_level(Some("reading")), 1);
+    assert_eq!(vault.category_for_section("## Books"), Some("reading"));
+    assert_eq!(vault.category_for_section("# books"), Some("reading"));
+    assert_eq!(vault.category_for_section("## Books to read"), None);
+
+    let err = vault.get_section_header(Some("garden")).unwrap_err().to_string();
+    assert!(err.contains("'garden'"));
+    assert!(err.contains("work, health, reading"));
+}
+
+#[test]
+fn test_sections_reject_shared_names() {
+    let yaml_content = r#"
+vaults:
+  test:
+    name: test
+    path: /tmp/journal
+    locale: en-US
+    phrases: {}
+    template_file: null
+    sections:
+      work:
+        header: "Work"
+        aliases: [job]
+      career:
+        header: "Career"
+        aliases: [Job]
+"#;
+    let err = serde_yaml_ng::from_str::<Config>(yaml_content).unwrap_err().to_string();
+    assert!(err.contains("'job' is used by both"));
+
+    // A category may not reuse another category's name as an alias either
+    let clash = yaml_content.replace("aliases: [Job]", "aliases: [work]");
+    assert!(serde_yaml_ng::from_str::<Config>(&clash).is_err());
+
+    let distinct = yaml_content.replace("aliases: [Job]", "aliases: [jobs]");
+    assert!(serde_yaml_ng::from_str::<Config>(&distinct).is_ok());
+}
+
+#[test]
+fn test_sections_legacy_entry_does_not_override() {
+    let mut vault = VaultConfig::test_config("test", "/tmp/journal");
+    vault.section_header_work = Some("Old Work".to_string());
+    vault.sections.insert("work".to_string(), journey::config::SectionConfig { header: "Work".to_string(), ..Default::default() });
+    vault.migrate_section_headers();
+
+    assert_eq!(vault.sections["work"].header, "Work");
+    assert_eq!(vault.section_header_work, None);
+}
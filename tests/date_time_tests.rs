@@ -1,5 +1,6 @@
-use journey::date_time::DateTimeHandler;
-use chrono::{NaiveDate, NaiveTime, Local};
+use journey::date_time::{Clock, DateRange, DateTimeHandler, Period, TimeExpr, Zone};
+use chrono::{Duration, NaiveDate, NaiveTime, Local, Weekday};
+use std::collections::HashMap;
 
 #[test]
 fn test_date_handler_creation() {
@@ -289,26 +290,31 @@ fn test_parse_relative_date() {
     let today = Local::now().date_naive();
     
     // Test 0 days (today)
-    let date = handler.parse_relative_date(0);
+    let date = handler.parse_relative_date(0).unwrap();
     assert_eq!(date, today);
     
     // Test positive values (past dates) - intuitive numbering
-    let date = handler.parse_relative_date(1);
+    let date = handler.parse_relative_date(1).unwrap();
     let yesterday = today - chrono::Duration::days(1);
     assert_eq!(date, yesterday);
     
-    let date = handler.parse_relative_date(7);
+    let date = handler.parse_relative_date(7).unwrap();
     let week_ago = today - chrono::Duration::days(7);
     assert_eq!(date, week_ago);
     
     // Test negative values (future dates) - intuitive numbering
-    let date = handler.parse_relative_date(-1);
+    let date = handler.parse_relative_date(-1).unwrap();
     let tomorrow = today + chrono::Duration::days(1);
     assert_eq!(date, tomorrow);
     
-    let date = handler.parse_relative_date(-7);
+    let date = handler.parse_relative_date(-7).unwrap();
     let week_from_now = today + chrono::Duration::days(7);
     assert_eq!(date, week_from_now);
+
+    // Offsets beyond the calendar are errors, not panics
+    assert!(handler.parse_relative_date(i64::MAX).is_err());
+    assert!(handler.parse_relative_date(i64::MIN).is_err());
+    assert!(handler.parse_relative_date(1_000_000_000).is_err());
 }
 
 #[test]
@@ -490,3 +496,261 @@ fn test_date_format_override_none() {
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), NaiveDate::from_ymd_opt(2025, 10, 24).unwrap());
 }
+
+// ============================================================================
+// DATE RANGE TESTS
+// ============================================================================
+
+#[test]
+fn test_parse_date_range() {
+    let handler = DateTimeHandler::new("en-US".to_string());
+
+    let range = handler.parse_date_range("2025-10-01..2025-10-15", None).unwrap();
+    assert_eq!(range.start, NaiveDate::from_ymd_opt(2025, 10, 1).unwrap());
+    assert_eq!(range.end, NaiveDate::from_ymd_opt(2025, 10, 15).unwrap());
+    assert_eq!(range.days().count(), 15);
+
+    assert!(handler.parse_date_range("2025-10-15..2025-10-01", None).is_err());
+    assert!(handler.parse_date_range("2025-10-01", None).is_err());
+}
+
+#[test]
+fn test_date_range_shorthands() {
+    // Friday 2025-10-24
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+
+    let week = DateRange::week_of(date, Weekday::Mon);
+    assert_eq!(week.start, NaiveDate::from_ymd_opt(2025, 10, 20).unwrap());
+    assert_eq!(week.end, NaiveDate::from_ymd_opt(2025, 10, 26).unwrap());
+
+    let month = DateRange::month_of(date);
+    assert_eq!(month.start, NaiveDate::from_ymd_opt(2025, 10, 1).unwrap());
+    assert_eq!(month.end, NaiveDate::from_ymd_opt(2025, 10, 31).unwrap());
+
+    let december = DateRange::month_of(NaiveDate::from_ymd_opt(2025, 12, 5).unwrap());
+    assert_eq!(december.end, NaiveDate::from_ymd_opt(2025, 12, 31).unwrap());
+
+    // Weeks can start on another day
+    let sunday_week = DateRange::week_of(date, Weekday::Sun);
+    assert_eq!(sunday_week.start, NaiveDate::from_ymd_opt(2025, 10, 19).unwrap());
+    assert_eq!(sunday_week.end, NaiveDate::from_ymd_opt(2025, 10, 25).unwrap());
+    assert_eq!(DateRange::week_of(sunday_week.start, Weekday::Sun), sunday_week);
+
+    let last = DateRange::last_days(date, 7);
+    assert_eq!(last.start, NaiveDate::from_ymd_opt(2025, 10, 18).unwrap());
+    assert!(last.contains(date));
+    assert_eq!(last.days().count(), 7);
+}
+
+#[test]
+fn test_period_ranges_and_labels() {
+    let date = NaiveDate::from_ymd_opt(2025, 11, 14).unwrap();
+
+    let quarter = Period::Quarter.range(date, Weekday::Mon);
+    assert_eq!(quarter.start, NaiveDate::from_ymd_opt(2025, 10, 1).unwrap());
+    assert_eq!(quarter.end, NaiveDate::from_ymd_opt(2025, 12, 31).unwrap());
+    assert_eq!(Period::Year.range(date, Weekday::Mon).days().count(), 365);
+
+    assert_eq!(Period::Week.label(date, Weekday::Mon), "2025-W46");
+    assert_eq!(Period::Month.label(date, Weekday::Mon), "2025-11");
+    assert_eq!(Period::Quarter.label(date, Weekday::Mon), "2025-Q4");
+    assert_eq!(Period::Year.label(date, Weekday::Mon), "2025");
+
+    // ISO weeks belong to the year of their Thursday
+    assert_eq!(Period::Week.label(NaiveDate::from_ymd_opt(2024, 12, 30).unwrap(), Weekday::Mon), "2025-W01");
+    // A Sunday-to-Saturday week is numbered after the Monday that follows its Sunday
+    assert_eq!(Period::Week.label(NaiveDate::from_ymd_opt(2025, 11, 9).unwrap(), Weekday::Sun), "2025-W46");
+    assert_eq!(Period::Week.label(date, Weekday::Sun), "2025-W46");
+}
+
+#[test]
+fn test_natural_dates() {
+    let handler = DateTimeHandler::new("en_US.UTF-8".to_string());
+    // A Thursday
+    let today = NaiveDate::from_ymd_opt(2025, 10, 23).unwrap();
+    let day = |d: u32| NaiveDate::from_ymd_opt(2025, 10, d);
+
+    assert_eq!(handler.parse_natural_date("today", today), day(23));
+    assert_eq!(handler.parse_natural_date("Yesterday", today), day(22));
+    assert_eq!(handler.parse_natural_date("tomorrow", today), day(24));
+    assert_eq!(handler.parse_natural_date("last friday", today), day(17));
+    assert_eq!(handler.parse_natural_date("last thursday", today), day(16));
+    assert_eq!(handler.parse_natural_date("next monday", today), day(27));
+    assert_eq!(handler.parse_natural_date("3 days ago", today), day(20));
+    assert_eq!(handler.parse_natural_date("2w ago", today), day(9));
+    assert_eq!(handler.parse_natural_date("in 2 days", today), day(25));
+    assert_eq!(handler.parse_natural_date("1 month ago", today), NaiveDate::from_ymd_opt(2025, 9, 23));
+
+    assert_eq!(handler.parse_natural_date("2025-W43-5", today), day(24));
+    assert_eq!(handler.parse_natural_date("2025-W43", today), day(20));
+    assert_eq!(handler.parse_natural_date("2025-297", today), day(24));
+
+    assert_eq!(handler.parse_natural_date("2025-W54-1", today), None);
+    assert_eq!(handler.parse_natural_date("last someday", today), None);
+    assert_eq!(handler.parse_natural_date("i går", today), None);
+}
+
+#[test]
+fn test_natural_dates_with_huge_amounts_are_rejected() {
+    let handler = DateTimeHandler::new("en_US.UTF-8".to_string());
+    let today = NaiveDate::from_ymd_opt(2025, 10, 23).unwrap();
+
+    for input in ["200000000000 days ago", "in 9000000000000000 weeks", "400000000 years ago", "in 4294967295 months", "99999999999999999999 days ago"] {
+        assert_eq!(handler.parse_natural_date(input, today), None, "{}", input);
+        assert!(handler.parse_date(input).is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn test_natural_dates_norwegian() {
+    let handler = DateTimeHandler::new("no_NO.UTF-8".to_string());
+    let today = NaiveDate::from_ymd_opt(2025, 10, 23).unwrap();
+    let day = |d: u32| NaiveDate::from_ymd_opt(2025, 10, d);
+
+    assert_eq!(handler.parse_natural_date("i dag", today), day(23));
+    assert_eq!(handler.parse_natural_date("I går", today), day(22));
+    assert_eq!(handler.parse_natural_date("i morgen", today), day(24));
+    assert_eq!(handler.parse_natural_date("forrige fredag", today), day(17));
+    assert_eq!(handler.parse_natural_date("neste mandag", today), day(27));
+    assert_eq!(handler.parse_natural_date("for 3 dager siden", today), day(20));
+    assert_eq!(handler.parse_natural_date("3 dager siden", today), day(20));
+    assert_eq!(handler.parse_natural_date("om 2 uker", today), NaiveDate::from_ymd_opt(2025, 11, 6));
+    // English keeps working
+    assert_eq!(handler.parse_natural_date("yesterday", today), day(22));
+}
+
+#[test]
+fn test_natural_dates_through_date_parsing() {
+    let handler = DateTimeHandler::new("en_US.UTF-8".to_string());
+    let today = Local::now().date_naive();
+
+    assert_eq!(handler.parse_date_with_format_override("yesterday", None).unwrap(), today - chrono::Duration::days(1));
+    // A format override doesn't disable relative dates
+    assert_eq!(handler.parse_date_with_format_override("today", Some("DD.MM.YYYY")).unwrap(), today);
+    assert!(handler.parse_date_with_format_override("someday", None).is_err());
+}
+
+#[test]
+fn test_time_expressions() {
+    let handler = DateTimeHandler::new("en_US.UTF-8".to_string());
+    let named = HashMap::from([("Lunch".to_string(), "11:30".to_string())]);
+    let parse = |s: &str| handler.parse_time_expression(s, None, &named).unwrap();
+    let at = |h: u32, m: u32| TimeExpr::At(NaiveTime::from_hms_opt(h, m, 0).unwrap());
+
+    assert_eq!(parse("14:30"), at(14, 30));
+    assert_eq!(parse("noon"), at(12, 0));
+    assert_eq!(parse("midnight"), at(0, 0));
+    assert_eq!(parse("at lunch"), at(11, 30));
+    assert_eq!(parse("lunch"), at(11, 30));
+    assert_eq!(parse("now"), TimeExpr::Offset(Duration::zero()));
+    assert_eq!(parse("-20m"), TimeExpr::Offset(Duration::minutes(-20)));
+    assert_eq!(parse("2h ago"), TimeExpr::Offset(Duration::hours(-2)));
+    assert_eq!(parse("in 15 minutes"), TimeExpr::Offset(Duration::minutes(15)));
+
+    assert!(handler.parse_time_expression("at dinner", None, &named).is_err());
+    assert!(handler.parse_time_expression("-20x", None, &named).is_err());
+    // Offsets beyond the calendar are errors, not panics
+    assert!(handler.parse_time_expression("-999999999999999m", None, &named).is_err());
+    assert!(handler.parse_time_expression("+999999999999999999h", None, &named).is_err());
+    assert!(handler.parse_time_expression("in 999999999999 minutes", None, &named).is_err());
+    // Norwegian phrases need a Norwegian locale
+    assert!(handler.parse_time_expression("for 20 min siden", None, &named).is_err());
+
+    let norwegian = DateTimeHandler::new("nb_NO.UTF-8".to_string());
+    assert_eq!(norwegian.parse_time_expression("for 20 min siden", None, &named).unwrap(), TimeExpr::Offset(Duration::minutes(-20)));
+    assert_eq!(norwegian.parse_time_expression("nå", None, &named).unwrap(), TimeExpr::Offset(Duration::zero()));
+}
+
+#[test]
+fn test_time_expression_resolves_across_midnight() {
+    let today = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    let now = today.and_hms_opt(0, 10, 0).unwrap();
+
+    let earlier = TimeExpr::Offset(Duration::minutes(-20)).resolve(today, now);
+    assert_eq!(earlier, NaiveDate::from_ymd_opt(2025, 10, 23).unwrap().and_hms_opt(23, 50, 0).unwrap());
+
+    // Offsets apply to the current time of day on the chosen date
+    let yesterday = NaiveDate::from_ymd_opt(2025, 10, 23).unwrap();
+    assert_eq!(TimeExpr::Offset(Duration::hours(-1)).resolve(yesterday, now), yesterday.and_hms_opt(23, 10, 0).unwrap() - Duration::days(1));
+
+    let clock = TimeExpr::At(NaiveTime::from_hms_opt(16, 45, 0).unwrap()).resolve(yesterday, now);
+    assert_eq!(clock, yesterday.and_hms_opt(16, 45, 0).unwrap());
+}
+
+#[test]
+fn test_zone_parsing() {
+    assert_eq!("local".parse::<Zone>().unwrap(), Zone::Local);
+    assert_eq!("Europe/Oslo".parse::<Zone>().unwrap().to_string(), "Europe/Oslo");
+    assert_eq!("+02:00".parse::<Zone>().unwrap().to_string(), "+02:00");
+    assert_eq!("-0530".parse::<Zone>().unwrap().to_string(), "-05:30");
+    assert!("Mars/Olympus".parse::<Zone>().is_err());
+    assert!("+2".parse::<Zone>().is_err());
+}
+
+#[test]
+fn test_dst_gap_and_overlap_in_named_zone() {
+    let handler = DateTimeHandler::new("en-US".to_string()).with_zone("Europe/Oslo".parse().unwrap());
+    let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+    // Clocks skip from 02:00 to 03:00 on 2025-03-30
+    let spring = NaiveDate::from_ymd_opt(2025, 3, 30).unwrap();
+    let err = handler.resolve_date_time(spring, time(2, 30)).unwrap_err();
+    assert!(err.to_string().contains("2025-03-30 02:30:00 is skipped by a daylight saving change in Europe/Oslo"));
+    // The lenient variant moves forward by the gap
+    assert_eq!(handler.combine_date_time(spring, time(2, 30)).to_rfc3339(), "2025-03-30T03:30:00+02:00");
+
+    // 02:30 happens twice on 2025-10-26; the first occurrence wins
+    let autumn = NaiveDate::from_ymd_opt(2025, 10, 26).unwrap();
+    assert_eq!(handler.resolve_date_time(autumn, time(2, 30)).unwrap().to_rfc3339(), "2025-10-26T02:30:00+02:00");
+
+    // An explicit offset picks the second one
+    let winter = DateTimeHandler::new("en-US".to_string()).with_zone("+01:00".parse().unwrap());
+    assert_eq!(winter.resolve_date_time(autumn, time(2, 30)).unwrap().to_rfc3339(), "2025-10-26T02:30:00+01:00");
+}
+
+#[test]
+fn test_fixed_clock() {
+    let clock: Clock = "2025-10-24T14:30:00".parse().unwrap();
+    let handler = DateTimeHandler::new("en-US".to_string()).with_zone("Europe/Oslo".parse().unwrap()).with_clock(clock);
+
+    assert_eq!(handler.get_current_datetime().to_rfc3339(), "2025-10-24T14:30:00+02:00");
+    assert_eq!(handler.parse_relative_date(1).unwrap(), NaiveDate::from_ymd_opt(2025, 10, 23).unwrap());
+    assert_eq!(handler.parse_date("last friday").unwrap(), NaiveDate::from_ymd_opt(2025, 10, 17).unwrap());
+
+    // An instant is shown in the handler's zone
+    let instant: Clock = "2025-10-24T23:30:00Z".parse().unwrap();
+    let handler = handler.with_clock(instant);
+    assert_eq!(handler.get_current_datetime().to_rfc3339(), "2025-10-25T01:30:00+02:00");
+
+    assert_eq!("2025-10-24 14:30".parse::<Clock>().unwrap(), clock);
+    assert!("tomorrow-ish".parse::<Clock>().is_err());
+}
+
+#[test]
+fn test_day_starts_at() {
+    let four = NaiveTime::from_hms_opt(4, 0, 0).unwrap();
+    let handler = DateTimeHandler::new("en-US".to_string())
+        .with_day_start(four)
+        .with_clock("2025-10-25T01:30:00".parse().unwrap());
+    let day = |d: u32| NaiveDate::from_ymd_opt(2025, 10, d).unwrap();
+
+    // Before 04:00 it is still the 24th
+    assert_eq!(handler.today(), day(24));
+    assert_eq!(handler.parse_relative_date(1).unwrap(), day(23));
+    assert_eq!(handler.parse_date("today").unwrap(), day(24));
+    assert_eq!(handler.logical_date(day(25).and_hms_opt(4, 0, 0).unwrap()), day(25));
+
+    // Times after midnight run past 24:00
+    assert_eq!(handler.format_note_time(day(25).and_hms_opt(1, 30, 0).unwrap()), "25:30:00");
+    assert_eq!(handler.format_note_time(day(24).and_hms_opt(23, 15, 0).unwrap()), "23:15:00");
+
+    // --time on a logical day: early times are the next calendar morning, offsets count from now
+    let late = handler.resolve_time_expression(TimeExpr::At(NaiveTime::from_hms_opt(2, 0, 0).unwrap()), day(20));
+    assert_eq!(late, day(21).and_hms_opt(2, 0, 0).unwrap());
+    let evening = handler.resolve_time_expression(TimeExpr::At(NaiveTime::from_hms_opt(22, 0, 0).unwrap()), day(20));
+    assert_eq!(evening, day(20).and_hms_opt(22, 0, 0).unwrap());
+    let now = handler.resolve_time_expression(TimeExpr::Offset(Duration::zero()), day(24));
+    assert_eq!(now, day(25).and_hms_opt(1, 30, 0).unwrap());
+    let earlier = handler.resolve_time_expression(TimeExpr::Offset(Duration::minutes(-20)), day(23));
+    assert_eq!(earlier, day(24).and_hms_opt(1, 10, 0).unwrap());
+}
@@ -1,7 +1,5 @@
 use journey::config::Config;
 use journey::config::VaultConfig;
-use std::collections::HashMap;
-use std::path::PathBuf;
 
 #[test]
 fn test_set_default_vault() {
@@ -0,0 +1,78 @@
+mod common;
+
+use common::{configured_vault, date};
+use journey::date_time::Period;
+use journey::digest::{Digest, DIGEST_HEADING};
+use journey::vault::Vault;
+use std::fs;
+use tempfile::TempDir;
+
+fn digest_vault() -> (Vault, TempDir) {
+    configured_vault(|config| {
+        config.section_header_work = Some("## Work Log".to_string());
+        config.section_header_health = Some("Health".to_string());
+    }, &[
+        (date(2025, 10, 20), "## Work Log\n\n- [09:00:00] Standup\n\n# Reading\n\n- [21:00:00] Dune, ch. 3\n"),
+        (date(2025, 10, 22), "- [07:00:00] Woke up early\n\n# Health\n\n- [18:00:00] Ran 5k\n\n## Work Log\n\n- [ ] [10:00:00] Review PR\n  needs tests\n"),
+        // Outside the week
+        (date(2025, 10, 27), "## Work Log\n\n- [09:00:00] Next week\n"),
+    ])
+}
+
+#[test]
+fn test_digest_groups_by_category_then_day() {
+    let (vault, _temp_dir) = digest_vault();
+    let digest = Digest::build(&vault, Period::Week, date(2025, 10, 23)).unwrap();
+
+    assert_eq!(digest.label, "2025-W43");
+    assert_eq!(digest.note_count(), 5);
+    assert_eq!(digest.day_count(), 2);
+
+    let titles: Vec<&str> = digest.groups.iter().map(|g| g.title.as_str()).collect();
+    assert_eq!(titles, vec!["Work Log", "Health", "Reading", "Other"]);
+    assert_eq!(digest.groups[0].category.as_deref(), Some("work"));
+    assert_eq!(digest.groups[2].category, None);
+    assert_eq!(digest.groups[0].notes.len(), 2);
+}
+
+#[test]
+fn test_digest_markdown() {
+    let (vault, _temp_dir) = digest_vault();
+    let markdown = Digest::build(&vault, Period::Week, date(2025, 10, 20)).unwrap().to_markdown(&vault);
+
+    assert!(markdown.starts_with("# Digest 2025-W43\n\n2025-10-20 to 2025-10-26: 5 notes on 2 days\n"));
+    assert!(markdown.contains("| Work Log | 2 |\n| Health | 1 |\n| Reading | 1 |\n| Other | 1 |\n"));
+    assert!(markdown.contains("## Work Log (2)\n\n### 2025-10-20 Monday\n\n- 09:00 Standup\n\n### 2025-10-22 Wednesday\n\n- [ ] 10:00 Review PR\n  needs tests\n"));
+    assert!(!markdown.contains("Next week"));
+}
+
+#[test]
+fn test_digest_month_spans_whole_month() {
+    let (vault, _temp_dir) = digest_vault();
+    let digest = Digest::build(&vault, Period::Month, date(2025, 10, 1)).unwrap();
+    assert_eq!(digest.label, "2025-10");
+    assert_eq!(digest.note_count(), 6);
+}
+
+#[test]
+fn test_write_digest_into_periodic_note_replaces_previous() {
+    let (vault, _temp_dir) = digest_vault();
+    let path = vault.get_period_path(Period::Week, date(2025, 10, 20));
+    fs::write(&path, "# Plans\n\n- Ship it\n").unwrap();
+
+    let markdown = Digest::build(&vault, Period::Week, date(2025, 10, 20)).unwrap().to_markdown(&vault);
+    vault.write_period_section(Period::Week, date(2025, 10, 20), DIGEST_HEADING, &markdown).unwrap();
+    vault.write_period_section(Period::Week, date(2025, 10, 20), DIGEST_HEADING, "# Digest 2025-W43\n\nrewritten\n").unwrap();
+
+    let content = fs::read_to_string(&path).unwrap();
+    assert_eq!(content, "# Plans\n\n- Ship it\n\n# Digest 2025-W43\n\nrewritten\n");
+}
+
+#[test]
+fn test_write_digest_creates_periodic_note() {
+    let (vault, _temp_dir) = digest_vault();
+    let path = vault.write_period_section(Period::Month, date(2025, 10, 9), DIGEST_HEADING, "# Digest 2025-10\n").unwrap();
+
+    assert!(path.ends_with("2025-10.md"));
+    assert_eq!(fs::read_to_string(path).unwrap(), "---\nperiod: 2025-10\n---\n\n# Digest 2025-10\n");
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::configured_vault;
+use journey::config::NoteFormat;
+use journey::export::{write_records, ExportFormat, NoteRecord};
+use journey::vault::Vault;
+use chrono::NaiveDate;
+use tempfile::TempDir;
+
+fn test_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 10, 24).unwrap()
+}
+
+fn vault_with(format: NoteFormat, days: &[(NaiveDate, &str)]) -> (Vault, TempDir) {
+    configured_vault(|config| config.list_type = Some(format), days)
+}
+
+fn records(vault: &Vault) -> Vec<NoteRecord> {
+    vault.collect_notes(None, None).unwrap().iter().map(|note| NoteRecord::from_note(vault, note)).collect()
+}
+
+#[test]
+fn test_bullet_and_table_vaults_export_identical_records() {
+    let (bullet, _b) = vault_with(NoteFormat::Bullet, &[(test_date(), "# Work\n\n- [09:00] Standup\n- [14:30:00] Deploy, then \"verify\"\n")]);
+    let (table, _t) = vault_with(NoteFormat::Table, &[(test_date(), "# Work\n\n| Time | Content |\n|------|----------|\n| 09:00:00 | Standup |\n| 14:30:00 | Deploy, then \"verify\" |\n")]);
+
+    let from_bullet = records(&bullet);
+    assert_eq!(from_bullet, records(&table));
+
+    let record = &from_bullet[1];
+    assert_eq!(record.date, "2025-10-24");
+    assert_eq!(record.time.as_deref(), Some("14:30:00"));
+    assert!(record.timestamp.as_deref().unwrap().starts_with("2025-10-24T14:30:00"));
+    assert_eq!(record.vault, "journal");
+    assert_eq!(record.section.as_deref(), Some("Work"));
+    assert_eq!(from_bullet[0].time.as_deref(), Some("09:00:00"));
+}
+
+#[test]
+fn test_json_and_ndjson_round_trip() {
+    let (vault, _temp_dir) = vault_with(NoteFormat::Bullet, &[(test_date(), "- [09:00:00] First\n- Untimed\n")]);
+    let records = records(&vault);
+
+    let mut json = Vec::new();
+    write_records(&records, ExportFormat::Json, &mut json).unwrap();
+    let parsed: Vec<NoteRecord> = serde_json::from_slice(&json).unwrap();
+    assert_eq!(parsed, records);
+    assert_eq!(parsed[1].time, None);
+    assert_eq!(parsed[1].timestamp, None);
+
+    let mut ndjson = Vec::new();
+    write_records(&records, ExportFormat::Ndjson, &mut ndjson).unwrap();
+    let lines: Vec<NoteRecord> = String::from_utf8(ndjson).unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines, records);
+}
+
+#[test]
+fn test_csv_export_quotes_and_headers() {
+    let (vault, _temp_dir) = vault_with(NoteFormat::Bullet, &[(test_date(), "- [09:00:00] Coffee, then \"work\"\n")]);
+
+    let mut csv = Vec::new();
+    write_records(&records(&vault), ExportFormat::Csv, &mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let mut lines = csv.lines();
+
+    assert_eq!(lines.next(), Some("date,time,timestamp,vault,section,content"));
+    let row = lines.next().unwrap();
+    assert!(row.starts_with("2025-10-24,09:00:00,2025-10-24T09:00:00"));
+    assert!(row.ends_with(",journal,,\"Coffee, then \"\"work\"\"\""));
+}
+
+fn ics_for(vault: &Vault, duration: Option<chrono::Duration>) -> String {
+    let mut ics = Vec::new();
+    journey::ics::write_calendar(&records(vault), duration, &mut ics).unwrap();
+    String::from_utf8(ics).unwrap()
+}
+
+#[test]
+fn test_ics_events() {
+    let (vault, _temp_dir) = vault_with(NoteFormat::Bullet, &[(test_date(), "# Work\n\n- [14:30:00] Deploy; then verify, carefully\n  second line\n- Untimed\n")]);
+    let ics = ics_for(&vault, Some(chrono::Duration::minutes(30)));
+
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+
+    let start = chrono::DateTime::parse_from_rfc3339(records(&vault)[0].timestamp.as_deref().unwrap()).unwrap();
+    let utc = start.with_timezone(&chrono::Utc).format("%Y%m%dT%H%M%SZ").to_string();
+    assert!(ics.contains(&format!("DTSTART:{}\r\n", utc)));
+    assert!(ics.contains("DURATION:PT30M\r\n"));
+    assert!(ics.contains(r"SUMMARY:Deploy\; then verify\, carefully"));
+    assert!(ics.contains(r"DESCRIPTION:Deploy\; then verify\, carefully\nsecond line"));
+    assert!(ics.contains("CATEGORIES:Work\r\n"));
+
+    // Notes without a time are all-day events
+    assert!(ics.contains("DTSTART;VALUE=DATE:20251024\r\nDTEND;VALUE=DATE:20251025\r\n"));
+}
+
+#[test]
+fn test_ics_uids_are_stable_across_content_edits() {
+    let (vault, _temp_dir) = vault_with(NoteFormat::Bullet, &[(test_date(), "- [09:00:00] Standup\n- [09:00:00] Same minute\n")]);
+    let uids = |ics: &str| ics.lines().filter(|l| l.starts_with("UID:")).map(str::to_string).collect::<Vec<_>>();
+
+    let first = uids(&ics_for(&vault, None));
+    assert_eq!(first.len(), 2);
+    assert_ne!(first[0], first[1]);
+
+    std::fs::write(vault.get_note_path(test_date()), "- [09:00:00] Standup (edited)\n- [09:00:00] Same minute\n").unwrap();
+    assert_eq!(uids(&ics_for(&vault, None)), first);
+}
+
+#[test]
+fn test_ics_folds_long_lines() {
+    let long = "word ".repeat(40);
+    let (vault, _temp_dir) = vault_with(NoteFormat::Bullet, &[(test_date(), &format!("- [09:00:00] {}\n", long.trim()))]);
+    let ics = ics_for(&vault, None);
+
+    assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+    let unfolded = ics.replace("\r\n ", "");
+    assert!(unfolded.contains(&format!("SUMMARY:{}\r\n", long.trim())));
+}
@@ -0,0 +1,147 @@
+mod common;
+
+use common::date;
+use journey::date_time::Zone;
+use journey::ics::{events_on, parse_calendar};
+use chrono::{NaiveDate, NaiveTime};
+
+fn summaries_on(ics: &str, day: NaiveDate) -> Vec<String> {
+    events_on(&parse_calendar(ics, Zone::Local), day).into_iter().map(|(_, summary)| summary).collect()
+}
+
+const CALENDAR: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+BEGIN:VEVENT\r
+UID:standup@example.com\r
+DTSTART:20251020T090000\r
+RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR\r
+EXDATE:20251024T090000\r
+SUMMARY:Standup\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:standup@example.com\r
+RECURRENCE-ID:20251027T090000\r
+DTSTART:20251027T100000\r
+SUMMARY:Standup (moved)\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:review@example.com\r
+DTSTART;TZID=Europe/Oslo:20251022T140000\r
+SUMMARY:Design review\\, round 2\r
+DESCRIPTION:A long description that is folded onto\r
+  a second line\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:retro@example.com\r
+DTSTART:20251001T150000\r
+RRULE:FREQ=DAILY;INTERVAL=2;COUNT=3\r
+SUMMARY:Retro\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:holiday@example.com\r
+DTSTART;VALUE=DATE:20251022\r
+SUMMARY:Company holiday\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:cancelled@example.com\r
+DTSTART:20251022T160000\r
+STATUS:CANCELLED\r
+SUMMARY:Cancelled sync\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+#[test]
+fn test_parse_calendar_events() {
+    let events = parse_calendar(CALENDAR, "Europe/Oslo".parse().unwrap());
+    assert_eq!(events.len(), 6);
+
+    let review = events.iter().find(|e| e.uid.as_deref() == Some("review@example.com")).unwrap();
+    assert_eq!(review.summary, "Design review, round 2");
+    assert_eq!(review.start, date(2025, 10, 22).and_hms_opt(14, 0, 0).unwrap());
+    assert!(events.iter().any(|e| e.all_day));
+}
+
+#[test]
+fn test_tzid_and_utc_times_convert_to_the_vault_zone() {
+    let tokyo: Zone = "Asia/Tokyo".parse().unwrap();
+    let events = parse_calendar(CALENDAR, tokyo);
+    let review = events.iter().find(|e| e.uid.as_deref() == Some("review@example.com")).unwrap();
+    // 14:00 CEST is 21:00 in Tokyo
+    assert_eq!(review.start, date(2025, 10, 22).and_hms_opt(21, 0, 0).unwrap());
+    // Floating times are left alone
+    let standup = events.iter().find(|e| e.summary == "Standup").unwrap();
+    assert_eq!(standup.start, date(2025, 10, 20).and_hms_opt(9, 0, 0).unwrap());
+
+    let utc = parse_calendar("BEGIN:VEVENT\nDTSTART:20251022T230000Z\nSUMMARY:Late call\nEND:VEVENT\n", tokyo);
+    assert_eq!(utc[0].start, date(2025, 10, 23).and_hms_opt(8, 0, 0).unwrap());
+}
+
+#[test]
+fn test_events_on_day_sorted_without_all_day_or_cancelled() {
+    let events = events_on(&parse_calendar(CALENDAR, Zone::Local), date(2025, 10, 22));
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].0.time(), NaiveTime::from_hms_opt(9, 0, 0).unwrap());
+    assert_eq!(events[0].1, "Standup");
+    assert_eq!(events[1].1, "Design review, round 2");
+}
+
+#[test]
+fn test_weekly_recurrence_with_exdate_and_override() {
+    assert_eq!(summaries_on(CALENDAR, date(2025, 10, 20)), vec!["Standup"]);
+    assert!(summaries_on(CALENDAR, date(2025, 10, 21)).is_empty());
+    // Excluded occurrence
+    assert!(summaries_on(CALENDAR, date(2025, 10, 24)).is_empty());
+    // Overridden occurrence shows the moved instance only
+    let moved = events_on(&parse_calendar(CALENDAR, Zone::Local), date(2025, 10, 27));
+    assert_eq!(moved.len(), 1);
+    assert_eq!(moved[0].1, "Standup (moved)");
+    assert_eq!(moved[0].0.time(), NaiveTime::from_hms_opt(10, 0, 0).unwrap());
+    // Far future occurrences still expand
+    assert_eq!(summaries_on(CALENDAR, date(2026, 3, 6)), vec!["Standup"]);
+}
+
+#[test]
+fn test_daily_recurrence_with_interval_and_count() {
+    assert_eq!(summaries_on(CALENDAR, date(2025, 10, 1)), vec!["Retro"]);
+    assert!(summaries_on(CALENDAR, date(2025, 10, 2)).is_empty());
+    assert_eq!(summaries_on(CALENDAR, date(2025, 10, 5)), vec!["Retro"]);
+    // Fourth occurrence is beyond COUNT=3
+    assert!(summaries_on(CALENDAR, date(2025, 10, 7)).is_empty());
+}
+
+#[test]
+fn test_count_includes_dtstart_and_excluded_dates() {
+    // The excluded second occurrence still uses up one of the three
+    let ics = "BEGIN:VEVENT\nDTSTART:20251001T150000\nRRULE:FREQ=DAILY;COUNT=3\nEXDATE:20251002T150000\nSUMMARY:Retro\nEND:VEVENT\n";
+    assert_eq!(summaries_on(ics, date(2025, 10, 1)), vec!["Retro"]);
+    assert!(summaries_on(ics, date(2025, 10, 2)).is_empty());
+    assert_eq!(summaries_on(ics, date(2025, 10, 3)), vec!["Retro"]);
+    assert!(summaries_on(ics, date(2025, 10, 4)).is_empty());
+
+    // A Wednesday DTSTART counts even though BYDAY only lists Monday
+    let ics = "BEGIN:VEVENT\nDTSTART:20251022T090000\nRRULE:FREQ=WEEKLY;BYDAY=MO;COUNT=2\nSUMMARY:Sync\nEND:VEVENT\n";
+    assert_eq!(summaries_on(ics, date(2025, 10, 22)), vec!["Sync"]);
+    assert_eq!(summaries_on(ics, date(2025, 10, 27)), vec!["Sync"]);
+    assert!(summaries_on(ics, date(2025, 11, 3)).is_empty());
+}
+
+#[test]
+fn test_until_is_read_in_the_vault_zone() {
+    // UNTIL is 08:00 on the 24th in Tokyo, after that morning's 07:00 occurrence
+    let ics = "BEGIN:VEVENT\nDTSTART;TZID=Asia/Tokyo:20251020T070000\nRRULE:FREQ=DAILY;UNTIL=20251023T230000Z\nSUMMARY:Gym\nEND:VEVENT\n";
+    let events = parse_calendar(ics, "Asia/Tokyo".parse().unwrap());
+    assert_eq!(events_on(&events, date(2025, 10, 24)).len(), 1);
+    assert!(events_on(&events, date(2025, 10, 25)).is_empty());
+}
+
+#[test]
+fn test_unsupported_rrule_only_keeps_first_occurrence() {
+    let ics = "BEGIN:VEVENT\nDTSTART:20251014T100000\nRRULE:FREQ=MONTHLY;BYDAY=2TU\nSUMMARY:Board\nEND:VEVENT\n";
+    assert_eq!(summaries_on(ics, date(2025, 10, 14)), vec!["Board"]);
+    assert!(summaries_on(ics, date(2025, 11, 11)).is_empty());
+
+    let ics = "BEGIN:VEVENT\nDTSTART:20250131T100000\nRRULE:FREQ=MONTHLY;UNTIL=20250430T000000Z\nSUMMARY:Billing\nEND:VEVENT\n";
+    assert_eq!(summaries_on(ics, date(2025, 3, 31)), vec!["Billing"]);
+    assert!(summaries_on(ics, date(2025, 5, 31)).is_empty());
+}
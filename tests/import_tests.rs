@@ -0,0 +1,147 @@
+use journey::config::VaultConfig;
+use journey::date_time::Zone;
+use journey::errors::JourneyError;
+use journey::import::{import_records, read_records, ImportFormat};
+use journey::vault::Vault;
+use chrono::NaiveDate;
+use tempfile::TempDir;
+
+fn create_test_vault() -> (Vault, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = VaultConfig::test_config("test", temp_dir.path().to_str().unwrap());
+    config.section_header_work = Some("Work".to_string());
+    (Vault::new(config), temp_dir)
+}
+
+#[test]
+fn test_read_csv_and_ndjson_records() {
+    let csv = "date,time,content,category\n2025-10-24,09:00,\"Standup, daily\",work\n2025-10-24,12:00,Lunch,\n";
+    let records = read_records(csv.as_bytes(), ImportFormat::Csv).unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].content, "Standup, daily");
+    assert_eq!(records[0].category.as_deref(), Some("work"));
+
+    // Records written by `journey export` carry extra fields
+    let ndjson = "{\"date\":\"2025-10-24\",\"time\":\"09:00:00\",\"timestamp\":\"2025-10-24T09:00:00+02:00\",\"vault\":\"old\",\"section\":null,\"content\":\"Standup\"}\n\n";
+    let records = read_records(ndjson.as_bytes(), ImportFormat::Ndjson).unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].time, "09:00:00");
+    assert_eq!(records[0].category, None);
+}
+
+#[test]
+fn test_import_batches_per_day_and_sorts() {
+    let (vault, _temp_dir) = create_test_vault();
+    let csv = "date,time,content,category\n2025-10-24,14:00,Deploy,\n2025-10-25,08:00,Gym,\n2025-10-24,09:00,Standup,\n2025-10-24,10:00,Review,work\n";
+    let records = read_records(csv.as_bytes(), ImportFormat::Csv).unwrap();
+
+    let summary = import_records(&vault, &records).unwrap();
+    assert_eq!(summary.files.len(), 2);
+    assert!(summary.files.iter().all(|f| f.created));
+    assert_eq!(summary.added(), 4);
+
+    let day = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    let notes = vault.read_all_notes(day).unwrap();
+    let contents: Vec<&str> = notes.iter().map(|n| n.content.as_str()).collect();
+    assert_eq!(contents, vec!["Standup", "Deploy", "Review"]);
+    assert_eq!(notes[2].section.as_deref(), Some("Work"));
+}
+
+#[test]
+fn test_import_skips_exact_duplicates() {
+    let (vault, _temp_dir) = create_test_vault();
+    let csv = "date,time,content\n2025-10-24,09:00,Standup\n2025-10-24,09:00,Standup\n2025-10-24,09:00,Standup notes\n";
+    let records = read_records(csv.as_bytes(), ImportFormat::Csv).unwrap();
+
+    let summary = import_records(&vault, &records).unwrap();
+    assert_eq!(summary.added(), 2);
+    assert_eq!(summary.skipped(), 1);
+
+    // Importing the same file again changes nothing
+    let before = std::fs::read_to_string(&summary.files[0].path).unwrap();
+    let again = import_records(&vault, &records).unwrap();
+    assert_eq!(again.added(), 0);
+    assert_eq!(again.skipped(), 3);
+    assert!(!again.files[0].created);
+    assert_eq!(std::fs::read_to_string(&summary.files[0].path).unwrap(), before);
+}
+
+#[test]
+fn test_import_rejects_bad_dates() {
+    let (vault, _temp_dir) = create_test_vault();
+    let csv = "date,time,content\nyesterday-ish,09:00,Oops\n";
+    let records = read_records(csv.as_bytes(), ImportFormat::Csv).unwrap();
+    assert!(import_records(&vault, &records).is_err());
+}
+
+#[test]
+fn test_import_rejects_unknown_categories_before_writing() {
+    let (vault, _temp_dir) = create_test_vault();
+    let csv = "date,time,content,category\n2025-10-24,09:00,Standup,work\n2025-10-25,10:00,Weeding,garden\n";
+    let records = read_records(csv.as_bytes(), ImportFormat::Csv).unwrap();
+
+    let err = import_records(&vault, &records).unwrap_err();
+    assert!(matches!(err, JourneyError::UnknownCategory(_)));
+    assert!(!vault.get_note_path(NaiveDate::from_ymd_opt(2025, 10, 24).unwrap()).exists());
+    assert!(!vault.get_note_path(NaiveDate::from_ymd_opt(2025, 10, 25).unwrap()).exists());
+}
+
+#[test]
+fn test_parse_jrnl_entries() {
+    let text = "[2025-10-24 09:15] Standup with @team. Discussed the release.\nSecond paragraph line\n\n[2025-10-24 02:30 PM] Mail alice@example.com about @deploy-plan\n";
+    let notes = journey::import::parse_jrnl(text, Zone::Local).unwrap();
+    assert_eq!(notes.len(), 2);
+
+    assert_eq!(notes[0].content, "Standup with #team. Discussed the release.\nSecond paragraph line");
+    assert_eq!(notes[0].timestamp.naive_local(), NaiveDate::from_ymd_opt(2025, 10, 24).unwrap().and_hms_opt(9, 15, 0).unwrap());
+
+    // Email addresses are not tags; 12-hour times are understood
+    assert_eq!(notes[1].content, "Mail alice@example.com about #deploy-plan");
+    assert_eq!(notes[1].timestamp.naive_local().time(), chrono::NaiveTime::from_hms_opt(14, 30, 0).unwrap());
+}
+
+#[test]
+fn test_parse_dayone_export() {
+    let json = r#"{
+        "metadata": {"version": "1.0"},
+        "entries": [
+            {
+                "creationDate": "2025-10-24T07:00:00Z",
+                "text": "Hiked to the summit\\. ![](dayone-moment://ABC123)\n\nGreat views",
+                "tags": ["outdoors", "weekend trip"]
+            },
+            {"creationDate": "2025-10-25T12:00:00Z", "text": "Already #outdoors", "tags": ["Outdoors"]},
+            {"creationDate": "2025-10-26T12:00:00Z", "text": ""}
+        ]
+    }"#;
+
+    let notes = journey::import::parse_dayone(json, Zone::Local).unwrap();
+    assert_eq!(notes.len(), 2);
+    assert_eq!(notes[0].content, "Hiked to the summit.\n\nGreat views #outdoors #weekend-trip");
+    assert_eq!(notes[0].timestamp, chrono::DateTime::parse_from_rfc3339("2025-10-24T07:00:00Z").unwrap());
+    assert_eq!(notes[1].content, "Already #outdoors");
+}
+
+#[test]
+fn test_parse_dayone_converts_to_vault_zone() {
+    let json = r#"{"entries": [{"creationDate": "2025-10-24T23:30:00Z", "text": "Late flight"}]}"#;
+    let notes = journey::import::parse_dayone(json, "Europe/Oslo".parse().unwrap()).unwrap();
+
+    // 23:30 UTC is 01:30 the next day in Oslo (CEST, +02:00)
+    assert_eq!(notes[0].timestamp.date_naive(), NaiveDate::from_ymd_opt(2025, 10, 25).unwrap());
+    assert_eq!(notes[0].timestamp.format("%H:%M %:z").to_string(), "01:30 +02:00");
+}
+
+#[test]
+fn test_import_jrnl_into_vault_is_idempotent() {
+    let (vault, _temp_dir) = create_test_vault();
+    let notes = journey::import::parse_jrnl("[2025-10-24 09:15] First\n[2025-10-24 08:00] Earlier\n", Zone::Local).unwrap();
+
+    let summary = journey::import::import_notes(&vault, notes.clone()).unwrap();
+    assert_eq!(summary.added(), 2);
+    assert_eq!(journey::import::import_notes(&vault, notes).unwrap().skipped(), 2);
+
+    let day = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    let contents: Vec<String> = vault.read_notes(day, None).unwrap().into_iter().map(|n| n.content).collect();
+    assert_eq!(contents, vec!["Earlier", "First"]);
+}
@@ -0,0 +1,265 @@
+use journey::config::{NoteFormat, VaultConfig};
+use journey::note::{Note, NoteCodec, TaskStatus};
+use journey::vault::Vault;
+use chrono::{NaiveDate, NaiveTime};
+use tempfile::TempDir;
+
+fn english_headers() -> (String, String) {
+    ("Time".to_string(), "Content".to_string())
+}
+
+fn test_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 10, 24).unwrap()
+}
+
+#[test]
+fn test_bullet_round_trip() {
+    let codec = NoteCodec::new(NoteFormat::Bullet, english_headers());
+    let lines = [
+        "- [14:30:00] Deployed the release",
+        "- [09:15] Short timestamp",
+        "- [10:00:00] Content with [brackets] and | pipes",
+        "- No timestamp at all",
+        "- [ ] Unchecked task",
+    ];
+
+    for line in lines {
+        let note = codec.parse(line, test_date()).unwrap();
+        assert_eq!(codec.render(&note), line);
+    }
+}
+
+#[test]
+fn test_table_round_trip() {
+    let codec = NoteCodec::new(NoteFormat::Table, english_headers());
+    let lines = [
+        "| 14:30:00 | Deployed the release |",
+        "| 10:00:00 | Content with a | pipe |",
+        "|  | No timestamp |",
+    ];
+
+    for line in lines {
+        let note = codec.parse(line, test_date()).unwrap();
+        assert_eq!(codec.render(&note), line);
+    }
+}
+
+#[test]
+fn test_bullet_parse_fields() {
+    let codec = NoteCodec::new(NoteFormat::Bullet, english_headers());
+    let note = codec.parse("- [14:30:00] Hello world", test_date()).unwrap();
+
+    assert_eq!(note.date, test_date());
+    assert_eq!(note.timestamp.as_deref(), Some("14:30:00"));
+    assert_eq!(note.time(), NaiveTime::from_hms_opt(14, 30, 0));
+    assert_eq!(note.content, "Hello world");
+}
+
+#[test]
+fn test_checkbox_is_not_a_timestamp() {
+    let codec = NoteCodec::new(NoteFormat::Bullet, english_headers());
+    let note = codec.parse("- [ ] Write tests", test_date()).unwrap();
+
+    assert_eq!(note.timestamp, None);
+    assert_eq!(note.task, Some(TaskStatus::Open));
+    assert_eq!(note.content, "Write tests");
+}
+
+#[test]
+fn test_task_round_trip() {
+    let bullet = NoteCodec::new(NoteFormat::Bullet, english_headers());
+    let table = NoteCodec::new(NoteFormat::Table, english_headers());
+
+    let note = bullet.parse("- [ ] [14:30] Fix flaky test", test_date()).unwrap();
+    assert_eq!(note.task, Some(TaskStatus::Open));
+    assert_eq!(note.timestamp.as_deref(), Some("14:30"));
+    assert_eq!(note.content, "Fix flaky test");
+    assert_eq!(bullet.render(&note), "- [ ] [14:30] Fix flaky test");
+
+    let done = bullet.parse("- [x] [09:00:00] Ship it ✅ 2025-10-24 10:15", test_date()).unwrap();
+    assert_eq!(done.task, Some(TaskStatus::Done));
+    assert_eq!(table.render(&done), "| 09:00:00 | [x] Ship it ✅ 2025-10-24 10:15 |");
+    assert_eq!(table.parse(&table.render(&done), test_date()).unwrap(), done);
+
+    // A link is neither a checkbox nor a timestamp
+    let link = bullet.parse("- [docs](https://example.com)", test_date()).unwrap();
+    assert_eq!(link.task, None);
+    assert_eq!(link.content, "[docs](https://example.com)");
+}
+
+#[test]
+fn test_table_headers_are_not_notes() {
+    let codec = NoteCodec::new(NoteFormat::Table, ("Tid".to_string(), "Innhold".to_string()));
+
+    assert!(codec.parse("| Tid | Innhold |", test_date()).is_none());
+    assert!(codec.parse("| Time | Content |", test_date()).is_none());
+    assert!(codec.parse("|------|----------|", test_date()).is_none());
+    assert!(codec.parse("| 10:00 | Time for Content review |", test_date()).is_some());
+}
+
+#[test]
+fn test_non_note_lines() {
+    let bullet = NoteCodec::new(NoteFormat::Bullet, english_headers());
+    let table = NoteCodec::new(NoteFormat::Table, english_headers());
+
+    for line in ["# Heading", "Plain paragraph", "", "---"] {
+        assert!(bullet.parse(line, test_date()).is_none(), "{}", line);
+        assert!(table.parse(line, test_date()).is_none(), "{}", line);
+    }
+}
+
+#[test]
+fn test_cross_format_render() {
+    let bullet = NoteCodec::new(NoteFormat::Bullet, english_headers());
+    let table = NoteCodec::new(NoteFormat::Table, english_headers());
+
+    let note = bullet.parse("- [11:00:00] Same note", test_date()).unwrap();
+    let row = table.render(&note);
+    assert_eq!(row, "| 11:00:00 | Same note |");
+    assert_eq!(table.parse(&row, test_date()).unwrap(), note);
+}
+
+#[test]
+fn test_note_ordering_untimed_last() {
+    let timed = Note::new(test_date(), Some("08:00".to_string()), "Timed");
+    let untimed = Note::new(test_date(), None, "Untimed");
+
+    assert_eq!(timed.cmp_by_time(&untimed), std::cmp::Ordering::Less);
+    assert_eq!(untimed.cmp_by_time(&timed), std::cmp::Ordering::Greater);
+}
+
+#[test]
+fn test_read_notes_sections_and_spans() {
+    let temp_dir = TempDir::new().unwrap();
+    let vault = Vault::new(VaultConfig::test_config("test", temp_dir.path().to_str().unwrap()));
+    let content = "---\ndate: 2025-10-24\ntags:\n  - journal\n---\n\n# Work\n\n- [11:00:00] Second\n- [09:00:00] First\n\n# Personal\n\n| 12:00:00 | Lunch |\n";
+    std::fs::write(vault.get_note_path(test_date()), content).unwrap();
+
+    let notes = vault.read_notes(test_date(), None).unwrap();
+    assert_eq!(notes.len(), 3);
+
+    assert_eq!(notes[0].content, "First");
+    assert_eq!(notes[0].section.as_deref(), Some("Work"));
+    assert_eq!(notes[0].line_span, 9..10);
+    assert_eq!(notes[0].source.as_ref(), Some(&vault.get_note_path(test_date())));
+
+    assert_eq!(notes[1].content, "Second");
+    assert_eq!(notes[2].content, "Lunch");
+    assert_eq!(notes[2].section.as_deref(), Some("Personal"));
+}
+
+#[test]
+fn test_extract_tags() {
+    let tags = journey::note::extract_tags("Rolled out #deploy and #incident/sev-2, see #42");
+    assert_eq!(tags, vec!["deploy", "incident/sev-2"]);
+
+    // Trailing punctuation is not part of the tag, duplicates are collapsed
+    let tags = journey::note::extract_tags("#deploy, then #deploy again.");
+    assert_eq!(tags, vec!["deploy"]);
+}
+
+#[test]
+fn test_extract_tags_ignores_headings_and_urls() {
+    assert!(journey::note::extract_tags("# Heading").is_empty());
+    assert!(journey::note::extract_tags("## Subheading text").is_empty());
+    assert!(journey::note::extract_tags("See https://example.com/docs#section").is_empty());
+    assert!(journey::note::extract_tags("issue#12 mid-word").is_empty());
+}
+
+#[test]
+fn test_note_has_tag_case_insensitive() {
+    let note = Note::new(test_date(), None, "Paged on #Incident");
+    assert!(note.has_tag("incident"));
+    assert!(note.has_tag("#INCIDENT"));
+    assert!(!note.has_tag("deploy"));
+}
+
+#[test]
+fn test_note_selector_by_time() {
+    use journey::note::NoteSelector;
+    let notes = vec![
+        Note::new(test_date(), Some("09:00:00".to_string()), "Standup"),
+        Note::new(test_date(), Some("14:30:00".to_string()), "Deploy"),
+        Note::new(test_date(), Some("14:30:45".to_string()), "Rollback"),
+    ];
+
+    let at = |h, m, s, with_seconds| NoteSelector::Time { time: NaiveTime::from_hms_opt(h, m, s).unwrap(), with_seconds };
+
+    assert_eq!(at(9, 0, 0, false).select(&notes).unwrap().content, "Standup");
+    assert_eq!(at(14, 30, 45, true).select(&notes).unwrap().content, "Rollback");
+
+    // Two notes within 14:30 is ambiguous; no note at 11:00 is not found
+    assert!(matches!(at(14, 30, 0, false).select(&notes), Err(journey::errors::JourneyError::AmbiguousNote(_))));
+    assert!(matches!(at(11, 0, 0, false).select(&notes), Err(journey::errors::JourneyError::NoteNotFound(_))));
+}
+
+#[test]
+fn test_note_selector_by_index_and_last() {
+    use journey::note::NoteSelector;
+    let notes = vec![
+        Note::new(test_date(), Some("09:00:00".to_string()), "First"),
+        Note::new(test_date(), Some("10:00:00".to_string()), "Second"),
+    ];
+
+    assert_eq!(NoteSelector::Index(1).select(&notes).unwrap().content, "First");
+    assert_eq!(NoteSelector::Last.select(&notes).unwrap().content, "Second");
+    assert!(NoteSelector::Index(0).select(&notes).is_err());
+    assert!(NoteSelector::Index(3).select(&notes).is_err());
+    assert!(NoteSelector::Last.select(&[]).is_err());
+}
+
+#[test]
+fn test_multiline_bullet_round_trip() {
+    let codec = NoteCodec::new(NoteFormat::Bullet, english_headers());
+    let lines = [
+        "- [14:30:00] Panic in worker",
+        "  thread 'main' panicked",
+        "",
+        "  \tat src/main.rs:10",
+        "",
+        "- [15:00:00] Next note",
+    ];
+
+    let note = codec.parse_block(&lines, test_date()).unwrap();
+    assert_eq!(note.content, "Panic in worker\nthread 'main' panicked\n\n\tat src/main.rs:10");
+    assert_eq!(note.line_span, 0..4);
+    assert_eq!(codec.render(&note), lines[..4].join("\n"));
+}
+
+#[test]
+fn test_multiline_table_uses_br() {
+    let bullet = NoteCodec::new(NoteFormat::Bullet, english_headers());
+    let table = NoteCodec::new(NoteFormat::Table, english_headers());
+
+    let note = Note::new(test_date(), Some("10:00:00".to_string()), "First line\nSecond line");
+    let row = table.render(&note);
+    assert_eq!(row, "| 10:00:00 | First line<br>Second line |");
+
+    let parsed = table.parse_block(&[row.as_str()], test_date()).unwrap();
+    assert_eq!(parsed.content, note.content);
+    assert_eq!(bullet.render(&parsed), "- [10:00:00] First line\n  Second line");
+}
+
+#[test]
+fn test_read_notes_keeps_multiline_blocks_together() {
+    let temp_dir = TempDir::new().unwrap();
+    let vault = Vault::new(VaultConfig::test_config("test", temp_dir.path().to_str().unwrap()));
+    let content = "- [11:00:00] Stack trace\n  at foo\n  at bar\n- [09:00:00] Earlier\n";
+    std::fs::write(vault.get_note_path(test_date()), content).unwrap();
+
+    let notes = vault.read_notes(test_date(), None).unwrap();
+    assert_eq!(notes.len(), 2);
+    assert_eq!(notes[0].content, "Earlier");
+    assert_eq!(notes[1].content, "Stack trace\nat foo\nat bar");
+    assert_eq!(notes[1].line_span, 0..3);
+
+    let listed = vault.list_notes(test_date()).unwrap();
+    assert_eq!(listed, vec!["- [09:00:00] Earlier", "- [11:00:00] Stack trace\n  at foo\n  at bar"]);
+}
+
+#[test]
+fn test_multiline_content_trims_surrounding_blank_lines() {
+    let content = journey::note::multiline_content("\n\nError: boom   \n    at main\n\n");
+    assert_eq!(content, "Error: boom\n    at main");
+    assert_eq!(journey::note::multiline_content("\n  \n"), "");
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use common::date;
+use journey::onthisday::{lookback_days, same_day_in_year};
+use chrono::NaiveDate;
+
+#[test]
+fn test_same_day_in_year_falls_back_from_leap_day() {
+    assert_eq!(same_day_in_year(date(2024, 10, 24), 2021), Some(date(2021, 10, 24)));
+    assert_eq!(same_day_in_year(date(2024, 2, 29), 2023), Some(date(2023, 2, 28)));
+    assert_eq!(same_day_in_year(date(2024, 2, 29), 2020), Some(date(2020, 2, 29)));
+}
+
+#[test]
+fn test_lookback_days_most_recent_year_first() {
+    let days = lookback_days(date(2025, 10, 24), 2022, false);
+    let labels: Vec<&str> = days.iter().map(|d| d.label.as_str()).collect();
+    assert_eq!(labels, vec!["2024", "2023", "2022"]);
+    assert_eq!(days[0].date, date(2024, 10, 24));
+
+    assert!(lookback_days(date(2025, 10, 24), 2025, false).is_empty());
+}
+
+#[test]
+fn test_lookback_days_recent_keep_weekday() {
+    let days = lookback_days(date(2025, 10, 24), 2025, true);
+    let recent: Vec<(&str, NaiveDate)> = days.iter().map(|d| (d.label.as_str(), d.date)).collect();
+    assert_eq!(recent, vec![
+        ("1 week ago", date(2025, 10, 17)),
+        ("1 month ago", date(2025, 9, 26)),
+        ("1 quarter ago", date(2025, 7, 25)),
+    ]);
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use common::{configured_vault, date};
+use journey::config::{NoteFormat, SectionConfig};
+use journey::publish::publish;
+use std::fs;
+use tempfile::TempDir;
+
+fn published_vault() -> (TempDir, TempDir) {
+    let (vault, vault_dir) = configured_vault(|config| {
+        config.list_type = Some(NoteFormat::Bullet);
+        config.section_header_work = Some("Work".to_string());
+    }, &[
+        (date(2025, 10, 24), "---\ntags: [daily]\n---\n# Work\n\n- [09:00:00] Standup <team>\n- [09:00:00] Same minute\n\n# Home\n\n- [18:00:00] Dinner\n"),
+        (date(2025, 10, 25), "- [10:00:00] Saturday\n"),
+        (date(2025, 11, 1), "- [08:00:00] November\n"),
+    ]);
+
+    let out = TempDir::new().unwrap();
+    let summary = publish(&vault, out.path()).unwrap();
+    assert_eq!(summary.days, 3);
+    (vault_dir, out)
+}
+
+fn read(out: &TempDir, relative: &str) -> String {
+    fs::read_to_string(out.path().join(relative)).unwrap()
+}
+
+#[test]
+fn test_publish_writes_day_and_index_pages() {
+    let (_vault, out) = published_vault();
+
+    for page in ["index.html", "style.css", "2025/index.html", "2025/10/index.html", "2025/11/index.html", "2025/10/24.html", "2025/11/01.html", "category/work.html"] {
+        assert!(out.path().join(page).exists(), "missing {}", page);
+    }
+    assert!(read(&out, "2025/index.html").contains("href=\"10/index.html\""));
+    assert!(read(&out, "2025/10/index.html").contains("href=\"24.html\""));
+}
+
+#[test]
+fn test_day_page_strips_frontmatter_and_anchors_timestamps() {
+    let (_vault, out) = published_vault();
+    let day = read(&out, "2025/10/24.html");
+
+    assert!(!day.contains("tags: [daily]"));
+    assert!(day.contains("<h2>Work</h2>"));
+    assert!(day.contains("<li id=\"t-090000\"><a class=\"time\" href=\"#t-090000\">09:00:00</a>Standup &lt;team&gt;</li>"));
+    assert!(day.contains("id=\"t-090000-2\""));
+    assert!(day.contains("../../2025/10/25.html"));
+}
+
+#[test]
+fn test_prev_next_navigation_crosses_months() {
+    let (_vault, out) = published_vault();
+    let last_october = read(&out, "2025/10/25.html");
+
+    assert!(last_october.contains("<a href=\"../../2025/10/24.html\">&larr; Previous</a>"));
+    assert!(last_october.contains("<a href=\"../../2025/11/01.html\">Next &rarr;</a>"));
+    assert!(!read(&out, "2025/10/24.html").contains("Previous</a>"));
+}
+
+#[test]
+fn test_category_page_links_to_day_anchors() {
+    let (_vault, out) = published_vault();
+    let work = read(&out, "category/work.html");
+
+    assert!(work.contains("href=\"../2025/10/24.html#t-090000\""));
+    assert!(work.contains("href=\"../2025/10/24.html#t-090000-2\""));
+    assert!(!work.contains("Dinner"));
+    assert!(!out.path().join("category/personal.html").exists());
+    assert!(read(&out, "index.html").contains("href=\"category/work.html\""));
+}
+
+#[test]
+fn test_published_site_is_offline() {
+    let (_vault, out) = published_vault();
+
+    for page in ["index.html", "2025/10/24.html", "category/work.html"] {
+        let html = read(&out, page);
+        assert!(!html.contains("http://") && !html.contains("https://"), "{} references remote assets", page);
+    }
+    assert!(read(&out, "2025/10/24.html").contains("href=\"../../style.css\""));
+}
+
+#[test]
+fn test_category_pages_use_safe_file_names() {
+    let section = |header: &str| SectionConfig { header: header.to_string(), aliases: Vec::new(), level: None, order: None };
+    let (vault, _vault_dir) = configured_vault(|config| {
+        config.sections.insert("../Side \"Projects\"".to_string(), section("Side"));
+        config.sections.insert("side-projects".to_string(), section("More side"));
+    }, &[(date(2025, 10, 24), "# Side\n\n- [09:00:00] Soldering\n")]);
+
+    let root = TempDir::new().unwrap();
+    let out = root.path().join("site");
+    publish(&vault, &out).unwrap();
+
+    assert!(fs::read_to_string(out.join("category/side-projects.html")).unwrap().contains("Soldering"));
+    assert!(out.join("category/side-projects-2.html").exists());
+    assert!(!root.path().join("Side \"Projects\".html").exists());
+    let index = fs::read_to_string(out.join("index.html")).unwrap();
+    assert!(index.contains("href=\"category/side-projects.html\""));
+    assert!(index.contains("href=\"category/side-projects-2.html\""));
+}
@@ -0,0 +1,92 @@
+use journey::config::VaultConfig;
+use journey::date_time::DateRange;
+use journey::search::{search_vault, SearchQuery};
+use journey::vault::Vault;
+use chrono::{Local, NaiveDate, NaiveTime, TimeZone};
+use tempfile::TempDir;
+
+fn create_test_vault() -> (Vault, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let config = VaultConfig::test_config("test", temp_dir.path().to_str().unwrap());
+    (Vault::new(config), temp_dir)
+}
+
+fn add_note_at(vault: &Vault, date: NaiveDate, hour: u32, content: &str) {
+    let time = NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+    let timestamp = Local.from_local_datetime(&date.and_time(time)).single().unwrap();
+    vault.add_note(content, Some(timestamp)).unwrap();
+}
+
+#[test]
+fn test_substring_query_is_case_insensitive() {
+    let query = SearchQuery::new("deploy", false, false).unwrap();
+    assert!(query.matches("Deployed the release"));
+    assert!(!query.matches("Rolled back"));
+
+    let query = SearchQuery::new("deploy", false, true).unwrap();
+    assert!(!query.matches("Deployed the release"));
+}
+
+#[test]
+fn test_regex_query() {
+    let query = SearchQuery::new(r"incident-\d+", true, false).unwrap();
+    assert!(query.matches("Opened INCIDENT-42"));
+    assert!(!query.matches("No incident today"));
+
+    assert!(SearchQuery::new("(", true, false).is_err());
+}
+
+#[test]
+fn test_search_vault_across_days() {
+    let (vault, _temp_dir) = create_test_vault();
+    let first = NaiveDate::from_ymd_opt(2025, 10, 1).unwrap();
+    let second = NaiveDate::from_ymd_opt(2025, 10, 3).unwrap();
+    add_note_at(&vault, first, 9, "Deploy to staging");
+    add_note_at(&vault, second, 14, "Deploy to production");
+    add_note_at(&vault, second, 10, "Coffee with the team");
+
+    let query = SearchQuery::new("deploy", false, false).unwrap();
+    let hits = search_vault(&vault, &query, None, None).unwrap();
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].date, first);
+    assert_eq!(hits[1].content, "Deploy to production");
+
+    let range = DateRange::single(second);
+    let hits = search_vault(&vault, &query, Some(range), None).unwrap();
+    assert_eq!(hits.len(), 1);
+}
+
+#[test]
+fn test_search_ignores_frontmatter_and_headers() {
+    let (vault, _temp_dir) = create_test_vault();
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    let content = "---\ndate: 2025-10-24\ntags:\n  - release\n---\n\n# Release notes\n\n| Time | Content |\n|------|----------|\n| 10:00:00 | Cut the release branch |\n";
+    std::fs::write(vault.get_note_path(date), content).unwrap();
+
+    let query = SearchQuery::new("release", false, false).unwrap();
+    let hits = search_vault(&vault, &query, None, None).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].content, "Cut the release branch");
+    assert_eq!(hits[0].section.as_deref(), Some("Release notes"));
+
+    let query = SearchQuery::new("time", false, false).unwrap();
+    assert!(search_vault(&vault, &query, None, None).unwrap().is_empty());
+}
+
+#[test]
+fn test_search_with_category_section() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = VaultConfig::test_config("test", temp_dir.path().to_str().unwrap());
+    config.section_header_work = Some("Work".to_string());
+    let vault = Vault::new(config);
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    let content = "# Work\n- [09:00:00] Review PR\n\n# Personal\n- [18:00:00] Review book\n";
+    std::fs::write(vault.get_note_path(date), content).unwrap();
+
+    let query = SearchQuery::new("review", false, false).unwrap();
+    assert_eq!(search_vault(&vault, &query, None, None).unwrap().len(), 2);
+
+    let hits = search_vault(&vault, &query, None, Some("work")).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].content, "Review PR");
+}
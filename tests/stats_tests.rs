@@ -0,0 +1,82 @@
+mod common;
+
+use common::{configured_vault, date};
+use journey::config::NoteFormat;
+use journey::stats::Stats;
+use journey::vault::Vault;
+use chrono::NaiveDate;
+use tempfile::TempDir;
+
+/// A vault using `format` with a "Work" section for the work category
+fn vault_with(format: NoteFormat, days: &[(NaiveDate, &str)]) -> (Vault, TempDir) {
+    configured_vault(|config| {
+        config.list_type = Some(format);
+        config.section_header_work = Some("Work".to_string());
+    }, days)
+}
+
+fn stats_for(vault: &Vault, today: NaiveDate) -> Stats {
+    Stats::compute(&vault.collect_notes(None, None).unwrap(), &vault.config, today)
+}
+
+#[test]
+fn test_bullet_and_table_vaults_give_identical_stats() {
+    let (bullet, _b) = vault_with(NoteFormat::Bullet, &[
+        (date(2025, 10, 20), "# Work\n\n- [09:00:00] Standup with the team\n- [09:30:00] Deploy\n\n# Home\n\n- [19:00:00] Dinner\n"),
+        (date(2025, 10, 21), "- [09:15:00] Coffee\n"),
+    ]);
+    let (table, _t) = vault_with(NoteFormat::Table, &[
+        (date(2025, 10, 20), "# Work\n\n| Time | Content |\n|------|----------|\n| 09:00:00 | Standup with the team |\n| 09:30:00 | Deploy |\n\n# Home\n\n| Time | Content |\n|------|----------|\n| 19:00:00 | Dinner |\n"),
+        (date(2025, 10, 21), "| Time | Content |\n|------|----------|\n| 09:15:00 | Coffee |\n"),
+    ]);
+
+    let stats = stats_for(&bullet, date(2025, 10, 21));
+    assert_eq!(stats, stats_for(&table, date(2025, 10, 21)));
+
+    assert_eq!(stats.days_with_entries, 2);
+    assert_eq!(stats.notes, 4);
+    assert_eq!(stats.notes_per_day_mean, 2.0);
+    assert_eq!(stats.notes_per_day_max, 3);
+    assert_eq!(stats.busiest_hour, Some(9));
+    assert_eq!(stats.busiest_hour_notes, 3);
+    assert_eq!(stats.words, 7);
+
+    assert_eq!(stats.sections[0].section, "Work");
+    assert_eq!(stats.sections[0].category.as_deref(), Some("work"));
+    assert_eq!((stats.sections[0].notes, stats.sections[0].words), (2, 5));
+    assert!(stats.sections.iter().any(|s| s.section == "(none)" && s.notes == 1));
+}
+
+#[test]
+fn test_streaks() {
+    let days: Vec<(NaiveDate, &str)> = [1, 2, 3, 5, 6, 9, 10]
+        .iter()
+        .map(|d| (date(2025, 10, *d), "- [08:00:00] Entry\n"))
+        .collect();
+    let (vault, _temp_dir) = vault_with(NoteFormat::Bullet, &days);
+
+    // Today already written
+    let stats = stats_for(&vault, date(2025, 10, 10));
+    assert_eq!(stats.longest_streak, 3);
+    assert_eq!(stats.current_streak, 2);
+
+    // Today not written yet: the streak up to yesterday still counts
+    assert_eq!(stats_for(&vault, date(2025, 10, 11)).current_streak, 2);
+    // A missed day breaks it
+    assert_eq!(stats_for(&vault, date(2025, 10, 12)).current_streak, 0);
+}
+
+#[test]
+fn test_empty_vault_stats_and_json() {
+    let (vault, _temp_dir) = vault_with(NoteFormat::Bullet, &[]);
+    let stats = stats_for(&vault, date(2025, 10, 10));
+
+    assert_eq!(stats.notes, 0);
+    assert_eq!(stats.first_day, None);
+    assert_eq!(stats.busiest_hour, None);
+    assert_eq!(stats.notes_per_day_mean, 0.0);
+
+    let json: serde_json::Value = serde_json::to_value(&stats).unwrap();
+    assert_eq!(json["days_with_entries"], 0);
+    assert!(json["sections"].as_array().unwrap().is_empty());
+}
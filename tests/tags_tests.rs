@@ -0,0 +1,33 @@
+use journey::note::Note;
+use journey::tags::tag_index;
+use chrono::NaiveDate;
+
+fn note_on(day: u32, content: &str) -> Note {
+    Note::new(NaiveDate::from_ymd_opt(2025, 10, day).unwrap(), None, content)
+}
+
+#[test]
+fn test_tag_index_counts_and_dates() {
+    let notes = vec![
+        note_on(3, "Rolled out #deploy"),
+        note_on(1, "Planned #deploy, paged on #incident"),
+        note_on(7, "Retro for #Incident and #deploy"),
+    ];
+
+    let index = tag_index(&notes);
+    assert_eq!(index.len(), 2);
+
+    assert_eq!(index[0].tag, "deploy");
+    assert_eq!(index[0].count, 3);
+    assert_eq!(index[0].first_seen, NaiveDate::from_ymd_opt(2025, 10, 1).unwrap());
+    assert_eq!(index[0].last_seen, NaiveDate::from_ymd_opt(2025, 10, 7).unwrap());
+
+    // Tags are grouped case-insensitively
+    assert_eq!(index[1].tag, "incident");
+    assert_eq!(index[1].count, 2);
+}
+
+#[test]
+fn test_tag_index_empty() {
+    assert!(tag_index(&[note_on(1, "No tags here")]).is_empty());
+}
@@ -1,6 +1,5 @@
 use journey::vault::Vault;
 use journey::config::VaultConfig;
-use std::collections::HashMap;
 use tempfile::TempDir;
 use chrono::{Local, NaiveDate, TimeZone};
 use std::fs;
@@ -39,7 +38,7 @@ updated: {created}
     
     // Test with a specific date to make results predictable
     let test_date = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(); // Wednesday
-    let timestamp = Local.from_local_date(&test_date).unwrap().and_hms_opt(14, 30, 0).unwrap();
+    let timestamp = Local.from_local_datetime(&test_date.and_hms_opt(14, 30, 0).unwrap()).unwrap();
     
     // Create a test note
     let result = vault.add_note("Test note content", Some(timestamp));
@@ -82,7 +81,7 @@ fn test_weekday_variations() {
     
     // Test with a specific date (Wednesday)
     let test_date = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
-    let timestamp = Local.from_local_date(&test_date).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let timestamp = Local.from_local_datetime(&test_date.and_hms_opt(12, 0, 0).unwrap()).unwrap();
     
     let result = vault.add_note("", Some(timestamp));
     assert!(result.is_ok());
@@ -1,7 +1,7 @@
 use journey::vault::Vault;
 use journey::config::{VaultConfig, NoteFormat};
+use journey::date_time::Period;
 use journey::errors::JourneyError;
-use std::collections::HashMap;
 use tempfile::TempDir;
 use chrono::{Local, NaiveDate, TimeZone};
 
@@ -762,3 +762,339 @@ fn test_note_format_conversion_with_section() {
     assert!(content.contains("# Other Section"));
     assert!(content.contains("| 12:00:00 | Other note |"));
 }
+
+#[test]
+fn test_daily_note_files_custom_path_format() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = VaultConfig::test_config("test", temp_dir.path().to_str().unwrap());
+    config.file_path_format = Some("{year}/{month:02}/{day:02}-{weekday_short}.md".to_string());
+    let vault = Vault::new(config);
+
+    let first = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    let second = NaiveDate::from_ymd_opt(2025, 1, 2).unwrap();
+    for date in [second, first] {
+        let timestamp = Local.from_local_datetime(&date.and_hms_opt(9, 0, 0).unwrap()).single().unwrap();
+        vault.add_note("Note", Some(timestamp)).unwrap();
+    }
+
+    // Non-daily markdown files are ignored
+    std::fs::write(temp_dir.path().join("README.md"), "# Readme\n").unwrap();
+
+    let files = vault.daily_note_files().unwrap();
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0], (first, vault.get_note_path(first)));
+    assert_eq!(files[1], (second, vault.get_note_path(second)));
+}
+
+#[test]
+fn test_remove_note_by_index() {
+    let (vault, _temp_dir) = create_test_vault();
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    let content = "---\ndate: 2025-10-24\n---\n\n- [11:00:00] Second\n- [09:00:00] First\n- [12:00:00] Third\n";
+    std::fs::write(vault.get_note_path(date), content).unwrap();
+
+    // Index follows the sorted --list order, not file order
+    let note = vault.select_note(date, None, &journey::note::NoteSelector::Index(2)).unwrap();
+    assert_eq!(note.content, "Second");
+
+    let removed = vault.remove_note(&note).unwrap();
+    assert_eq!(removed, "- [11:00:00] Second");
+
+    let content = std::fs::read_to_string(vault.get_note_path(date)).unwrap();
+    assert_eq!(content, "---\ndate: 2025-10-24\n---\n\n- [09:00:00] First\n- [12:00:00] Third\n");
+}
+
+#[test]
+fn test_amend_note_expands_phrases_and_keeps_format() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = VaultConfig::test_config("test", temp_dir.path().to_str().unwrap());
+    config.phrases.insert("@pr".to_string(), "pull request".to_string());
+    let vault = Vault::new(config);
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    std::fs::write(vault.get_note_path(date), "| Time | Content |\n|------|----------|\n| 10:00:00 | Reviwed PR |\n").unwrap();
+
+    let note = vault.select_note(date, None, &journey::note::NoteSelector::Last).unwrap();
+    let amended = vault.amend_note(&note, "Reviewed @pr").unwrap();
+    assert_eq!(amended, "| 10:00:00 | Reviewed pull request |");
+
+    let content = std::fs::read_to_string(vault.get_note_path(date)).unwrap();
+    assert!(content.contains("| 10:00:00 | Reviewed pull request |"));
+    assert!(!content.contains("Reviwed"));
+}
+
+#[test]
+fn test_move_note_to_another_day_section_and_format() {
+    let temp_dir = TempDir::new().unwrap();
+    let source = Vault::new(VaultConfig::test_config("source", temp_dir.path().join("a").to_str().unwrap()));
+    let mut target_config = VaultConfig::test_config("target", temp_dir.path().join("b").to_str().unwrap());
+    target_config.list_type = Some(NoteFormat::Table);
+    target_config.section_header_work = Some("Work".to_string());
+    let target = Vault::new(target_config);
+    std::fs::create_dir_all(&source.config.path).unwrap();
+
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    std::fs::write(source.get_note_path(date), "- [09:00:00] Standup\n- [14:30:00] Logged on the wrong day\n").unwrap();
+
+    let note = source.select_note(date, None, &journey::note::NoteSelector::Last).unwrap();
+    let to_date = NaiveDate::from_ymd_opt(2025, 10, 25).unwrap();
+    let timestamp = Local.from_local_datetime(&to_date.and_hms_opt(8, 15, 0).unwrap()).single().unwrap();
+    source.move_note(&note, &target, timestamp, Some("work")).unwrap();
+
+    let remaining = std::fs::read_to_string(source.get_note_path(date)).unwrap();
+    assert_eq!(remaining, "- [09:00:00] Standup\n");
+
+    let moved = target.read_notes(to_date, Some("work")).unwrap();
+    assert_eq!(moved.len(), 1);
+    assert_eq!(moved[0].content, "Logged on the wrong day");
+    assert_eq!(moved[0].time(), chrono::NaiveTime::from_hms_opt(8, 15, 0));
+    let content = std::fs::read_to_string(target.get_note_path(to_date)).unwrap();
+    assert!(content.contains("# Work"));
+    assert!(content.contains("| 08:15:00 | Logged on the wrong day |"));
+}
+
+#[test]
+fn test_move_note_does_not_expand_phrases_twice() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = VaultConfig::test_config("test", temp_dir.path().to_str().unwrap());
+    config.phrases.insert("pr".to_string(), "pull request pr".to_string());
+    let vault = Vault::new(config);
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    std::fs::write(vault.get_note_path(date), "- [10:00:00] Opened pull request pr\n").unwrap();
+
+    let note = vault.select_note(date, None, &journey::note::NoteSelector::Last).unwrap();
+    let timestamp = Local.from_local_datetime(&date.and_hms_opt(11, 0, 0).unwrap()).single().unwrap();
+    vault.move_note(&note, &vault, timestamp, None).unwrap();
+
+    let content = std::fs::read_to_string(vault.get_note_path(date)).unwrap();
+    assert_eq!(content, "- [11:00:00] Opened pull request pr\n");
+}
+
+#[test]
+fn test_insert_does_not_split_multiline_block() {
+    let (vault, _temp_dir) = create_test_vault();
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    std::fs::write(vault.get_note_path(date), "- [09:00:00] Paragraph\n  continued here\n- [12:00:00] Lunch\n").unwrap();
+
+    let timestamp = Local.from_local_datetime(&date.and_hms_opt(10, 0, 0).unwrap()).single().unwrap();
+    vault.add_note("Second\nwith detail", Some(timestamp)).unwrap();
+
+    let content = std::fs::read_to_string(vault.get_note_path(date)).unwrap();
+    assert_eq!(content, "- [09:00:00] Paragraph\n  continued here\n- [10:00:00] Second\n  with detail\n- [12:00:00] Lunch\n");
+}
+
+#[test]
+fn test_convert_multiline_bullet_to_table() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = VaultConfig::test_config("test", temp_dir.path().to_str().unwrap());
+    config.list_type = Some(NoteFormat::Table);
+    let vault = Vault::new(config);
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    std::fs::write(vault.get_note_path(date), "- [09:00:00] Paragraph\n  continued here\n").unwrap();
+
+    let timestamp = Local.from_local_datetime(&date.and_hms_opt(10, 0, 0).unwrap()).single().unwrap();
+    vault.add_note("Follow-up", Some(timestamp)).unwrap();
+
+    let content = std::fs::read_to_string(vault.get_note_path(date)).unwrap();
+    assert!(content.contains("| 09:00:00 | Paragraph<br>continued here |\n| 10:00:00 | Follow-up |"));
+    assert!(!content.contains("  continued here"));
+}
+
+#[test]
+fn test_add_and_complete_task() {
+    let (vault, _temp_dir) = create_test_vault();
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    let at = |h, m| Local.from_local_datetime(&date.and_hms_opt(h, m, 0).unwrap()).single().unwrap();
+
+    vault.add_note("Standup", Some(at(9, 0))).unwrap();
+    vault.add_task_with_category("Fix flaky test", Some(at(14, 30)), None).unwrap();
+
+    let content = std::fs::read_to_string(vault.get_note_path(date)).unwrap();
+    assert!(content.contains("- [ ] [14:30:00] Fix flaky test\n"));
+
+    let open: Vec<_> = vault.collect_notes(None, None).unwrap().into_iter().filter(|n| n.is_open_task()).collect();
+    assert_eq!(open.len(), 1);
+
+    let standup = vault.select_note(date, None, &journey::note::NoteSelector::Index(1)).unwrap();
+    assert!(matches!(vault.complete_task(&standup, at(15, 0)), Err(JourneyError::NotOpenTask(_))));
+
+    let task = vault.select_note(date, None, &journey::note::NoteSelector::Last).unwrap();
+    let done = vault.complete_task(&task, at(15, 5)).unwrap();
+    assert_eq!(done, "- [x] [14:30:00] Fix flaky test ✅ 2025-10-24 15:05");
+
+    assert!(vault.collect_notes(None, None).unwrap().iter().all(|n| !n.is_open_task()));
+    let task = vault.select_note(date, None, &journey::note::NoteSelector::Last).unwrap();
+    assert!(matches!(vault.complete_task(&task, at(15, 10)), Err(JourneyError::NotOpenTask(_))));
+}
+
+#[test]
+fn test_calendar_meetings_go_to_meetings_section_once() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = VaultConfig::test_config("test", temp_dir.path().to_str().unwrap());
+    config.section_header_meetings = Some("Meetings".to_string());
+    let vault = Vault::new(config);
+    let date = NaiveDate::from_ymd_opt(2025, 10, 22).unwrap();
+
+    let ics = "BEGIN:VEVENT\nDTSTART:20251020T090000\nRRULE:FREQ=DAILY\nSUMMARY:Standup\nEND:VEVENT\nBEGIN:VEVENT\nDTSTART:20251022T080000\nSUMMARY:Breakfast sync\nEND:VEVENT\n";
+    let notes: Vec<journey::vault::PendingNote> = journey::ics::events_on(&journey::ics::parse_calendar(ics, journey::date_time::Zone::Local), date)
+        .into_iter()
+        .map(|(start, summary)| journey::vault::PendingNote {
+            content: summary,
+            timestamp: Local.from_local_datetime(&start).single().unwrap().fixed_offset(),
+            category: Some("meetings".to_string()),
+        })
+        .collect();
+
+    assert_eq!(vault.add_notes(date, &notes).unwrap().added, 2);
+    let again = vault.add_notes(date, &notes).unwrap();
+    assert_eq!((again.added, again.skipped), (0, 2));
+
+    let meetings = vault.read_notes(date, Some("meetings")).unwrap();
+    let contents: Vec<&str> = meetings.iter().map(|n| n.content.as_str()).collect();
+    assert_eq!(contents, vec!["Breakfast sync", "Standup"]);
+    assert!(meetings.iter().all(|n| n.section.as_deref() == Some("Meetings")));
+}
+
+#[test]
+fn test_period_paths_use_defaults_and_configured_formats() {
+    let (mut vault, _temp_dir) = create_test_vault();
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+
+    assert!(vault.get_period_path(Period::Week, date).ends_with("2025-W43.md"));
+    assert!(vault.get_period_path(Period::Month, date).ends_with("2025-10.md"));
+    assert!(vault.get_period_path(Period::Quarter, date).ends_with("2025-Q4.md"));
+    assert!(vault.get_period_path(Period::Year, date).ends_with("2025.md"));
+
+    vault.config.weekly_format = Some("weekly/{week_year}/W{week:02}.md".to_string());
+    vault.config.monthly_format = Some("monthly/{year}/{Month}.md".to_string());
+    assert!(vault.get_period_path(Period::Week, date).ends_with("weekly/2025/W43.md"));
+    assert!(vault.get_period_path(Period::Month, date).ends_with("monthly/2025/October.md"));
+}
+
+#[test]
+fn test_weekly_notes_follow_first_day_of_week() {
+    let (mut vault, _temp_dir) = create_test_vault();
+    let sunday = NaiveDate::from_ymd_opt(2025, 10, 26).unwrap();
+    let saturday = NaiveDate::from_ymd_opt(2025, 11, 1).unwrap();
+    assert!(vault.get_period_path(Period::Week, sunday).ends_with("2025-W43.md"));
+
+    vault.config.first_day_of_week = Some(chrono::Weekday::Sun);
+    assert!(vault.get_period_path(Period::Week, sunday).ends_with("2025-W44.md"));
+    assert_eq!(vault.get_period_path(Period::Week, saturday), vault.get_period_path(Period::Week, sunday));
+}
+
+#[test]
+fn test_period_notes_are_dated_and_chronological() {
+    let (vault, _temp_dir) = create_test_vault();
+    let tuesday = Local.with_ymd_and_hms(2025, 10, 21, 9, 0, 0).unwrap();
+    let monday = Local.with_ymd_and_hms(2025, 10, 20, 15, 0, 0).unwrap();
+
+    vault.add_period_note(Period::Week, "Tuesday plan", None, tuesday, None).unwrap();
+    vault.add_period_note(Period::Week, "Monday retro", None, monday, None).unwrap();
+
+    let content = std::fs::read_to_string(vault.get_period_path(Period::Week, monday.date_naive())).unwrap();
+    assert!(content.starts_with("---\nperiod: 2025-W43\n---\n"));
+    let monday_at = content.find("- [2025-10-20 15:00:00] Monday retro").unwrap();
+    let tuesday_at = content.find("- [2025-10-21 09:00:00] Tuesday plan").unwrap();
+    assert!(monday_at < tuesday_at);
+
+    let listed = vault.list_period_notes(Period::Week, tuesday.date_naive(), None).unwrap();
+    assert_eq!(listed, vec!["- [2025-10-20 15:00:00] Monday retro", "- [2025-10-21 09:00:00] Tuesday plan"]);
+    assert!(vault.list_period_notes(Period::Month, tuesday.date_naive(), None).unwrap().is_empty());
+    assert!(vault.read_notes(tuesday.date_naive(), None).unwrap().is_empty());
+}
+
+#[test]
+fn test_period_note_created_from_period_template() {
+    let (mut vault, temp_dir) = create_test_vault();
+    let template = temp_dir.path().join("quarter.md");
+    std::fs::write(&template, "# {{period}} ({{period_start}} to {{period_end}})\n\n## Goals\n\n{{note}}").unwrap();
+    vault.config.quarterly_template = Some(template.to_string_lossy().to_string());
+    vault.config.template_file = Some(temp_dir.path().join("missing-daily.md").to_string_lossy().to_string());
+
+    let timestamp = Local.with_ymd_and_hms(2025, 11, 3, 8, 30, 0).unwrap();
+    vault.add_period_note(Period::Quarter, "Ship v1", Some(journey::note::TaskStatus::Open), timestamp, None).unwrap();
+
+    let content = std::fs::read_to_string(vault.get_period_path(Period::Quarter, timestamp.date_naive())).unwrap();
+    assert_eq!(content, "# 2025-Q4 (2025-10-01 to 2025-12-31)\n\n## Goals\n\n- [ ] [2025-11-03 08:30:00] Ship v1\n");
+}
+
+#[test]
+fn test_recorded_utc_offsets() {
+    let (mut vault, _temp_dir) = create_test_vault();
+    vault.config.record_utc_offset = true;
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    let tokyo = chrono::FixedOffset::east_opt(9 * 3600).unwrap();
+    let oslo = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+
+    vault.add_note("Landed", Some(tokyo.from_local_datetime(&date.and_hms_opt(14, 30, 0).unwrap()).unwrap())).unwrap();
+    vault.add_note("Packed", Some(oslo.from_local_datetime(&date.and_hms_opt(8, 0, 0).unwrap()).unwrap())).unwrap();
+
+    let content = std::fs::read_to_string(vault.get_note_path(date)).unwrap();
+    assert!(content.contains("- [08:00:00 +02:00] Packed\n- [14:30:00 +09:00] Landed"));
+
+    let notes = vault.read_notes(date, None).unwrap();
+    assert_eq!(notes[1].time(), chrono::NaiveTime::from_hms_opt(14, 30, 0));
+    assert_eq!(notes[1].offset(), Some(tokyo));
+    assert_eq!(notes[0].offset(), Some(oslo));
+}
+
+#[test]
+fn test_notes_without_timestamp_use_the_vault_clock() {
+    let (vault, _temp_dir) = create_test_vault();
+    let vault = vault.with_clock("2025-10-24T14:30:00".parse().unwrap());
+
+    vault.add_note::<Local>("Pinned", None).unwrap();
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    assert_eq!(vault.list_notes(date).unwrap(), vec!["- [14:30:00] Pinned"]);
+}
+
+#[test]
+fn test_day_starts_at_keeps_late_notes_on_the_previous_day() {
+    let (vault, _temp_dir) = create_test_vault();
+    let mut config = vault.config.clone();
+    config.day_starts_at = chrono::NaiveTime::from_hms_opt(4, 0, 0);
+    let vault = Vault::new(config).with_clock("2025-10-25T01:30:00".parse().unwrap());
+    let evening = Local.from_local_datetime(&NaiveDate::from_ymd_opt(2025, 10, 24).unwrap().and_hms_opt(23, 0, 0).unwrap()).unwrap();
+
+    vault.add_note::<Local>("Still up", None).unwrap();
+    vault.add_note("Dinner", Some(evening)).unwrap();
+
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    assert_eq!(vault.list_notes(date).unwrap(), vec!["- [23:00:00] Dinner", "- [25:30:00] Still up"]);
+    assert!(!vault.get_note_path(NaiveDate::from_ymd_opt(2025, 10, 25).unwrap()).exists());
+
+    let notes = vault.read_notes(date, None).unwrap();
+    assert_eq!(notes[1].time(), chrono::NaiveTime::from_hms_opt(1, 30, 0));
+    assert_eq!(notes[1].datetime(), NaiveDate::from_ymd_opt(2025, 10, 25).unwrap().and_hms_opt(1, 30, 0));
+}
+
+#[test]
+fn test_user_defined_categories() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = VaultConfig::test_config("test", temp_dir.path().to_str().unwrap());
+    config.sections.insert("reading".to_string(), journey::config::SectionConfig {
+        header: "Books".to_string(),
+        aliases: vec!["books".to_string()],
+        level: Some(2),
+        order: None,
+    });
+    let vault = Vault::new(config);
+    let date = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+    let timestamp = Local.from_local_datetime(&date.and_hms_opt(21, 0, 0).unwrap()).unwrap();
+
+    vault.add_note_with_category("Finished Dune", Some(timestamp), Some("books")).unwrap();
+    let content = std::fs::read_to_string(vault.get_note_path(date)).unwrap();
+    assert!(content.contains("## Books\n\n- [21:00:00] Finished Dune"));
+    assert_eq!(vault.list_notes_with_category(date, Some("reading")).unwrap(), vec!["- [21:00:00] Finished Dune"]);
+
+    let err = vault.add_note_with_category("Weeded", Some(timestamp), Some("garden")).unwrap_err();
+    assert!(matches!(err, JourneyError::UnknownCategory(ref msg) if msg.contains("valid categories: reading")));
+
+    // An unknown category is rejected on a day without a file too, and nothing is written
+    let empty_day = NaiveDate::from_ymd_opt(2025, 10, 25).unwrap();
+    let timestamp = Local.from_local_datetime(&empty_day.and_hms_opt(9, 0, 0).unwrap()).unwrap();
+    let err = vault.add_note_with_category("Weeded", Some(timestamp), Some("garden")).unwrap_err();
+    assert!(matches!(err, JourneyError::UnknownCategory(_)));
+    assert!(!vault.get_note_path(empty_day).exists());
+    assert!(vault.list_notes_with_category(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(), Some("garden")).is_err());
+}